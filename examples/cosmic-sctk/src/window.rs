@@ -235,15 +235,19 @@ impl Application for Window {
                 .condensed(condensed)
                 .into();
 
-            let choose_theme = [Theme::Light, Theme::Dark].iter().fold(
+            let themes = [
+                Theme::Light,
+                Theme::Dark,
+                Theme::HighContrastLight,
+                Theme::HighContrastDark,
+            ];
+            let selected_theme = themes.iter().position(|theme| *theme == self.theme);
+            let choose_theme = themes.iter().enumerate().fold(
                 row![].spacing(10).align_items(Alignment::Center),
-                |row, theme| {
-                    row.push(radio(
-                        format!("{:?}", theme),
-                        *theme,
-                        Some(self.theme),
-                        Message::ThemeChanged,
-                    ))
+                |row, (nth, theme)| {
+                    row.push(radio(format!("{:?}", theme), nth, selected_theme, |_| {
+                        Message::ThemeChanged(theme.clone())
+                    }))
                 },
             );
             let secondary = button(ButtonTheme::Secondary)
@@ -349,7 +353,7 @@ impl Application for Window {
     }
 
     fn theme(&self) -> Theme {
-        self.theme
+        self.theme.clone()
     }
 
     fn close_requested(&self, id: SurfaceIdWrapper) -> Self::Message {
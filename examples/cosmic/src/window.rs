@@ -10,13 +10,16 @@ use cosmic::{
     iced_native,
     iced_native::{subscription, window},
     iced_winit::window::{close, drag, minimize, toggle_maximize},
-    theme::{self, Theme},
-    widget::{header_bar, icon, list, nav_bar, nav_button, scrollable, settings},
+    theme::{self, Theme, Transition},
+    widget::{
+        header_bar, icon, list, nav_bar, nav_button, scrollable, settings, text as typography,
+    },
     Element, ElementExt,
 };
 use once_cell::sync::Lazy;
 use std::{
     sync::atomic::{AtomicU32, Ordering},
+    time::{Duration, Instant},
     vec,
 };
 
@@ -125,6 +128,7 @@ impl Default for Page {
 
 static WINDOW_WIDTH: AtomicU32 = AtomicU32::new(0);
 const BREAK_POINT: u32 = 900;
+const THEME_TRANSITION: Duration = Duration::from_millis(300);
 
 #[derive(Default)]
 pub struct Window {
@@ -132,6 +136,7 @@ pub struct Window {
     page: Page,
     debug: bool,
     theme: Theme,
+    theme_transition: Option<Transition>,
     bluetooth: bluetooth::State,
     demo: demo::State,
     desktop: desktop::State,
@@ -160,7 +165,7 @@ impl Window {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum Message {
     Close,
     CondensedViewToggle,
@@ -173,6 +178,7 @@ pub enum Message {
     Maximize,
     Minimize,
     Page(Page),
+    ThemeTick(Instant),
     ToggleSidebar,
     ToggleSidebarCondensed,
 }
@@ -185,7 +191,11 @@ impl From<Page> for Message {
 
 impl Window {
     fn page_title<Message: 'static>(&self, page: Page) -> Element<Message> {
-        row!(text(page.title()).size(30), horizontal_space(Length::Fill),).into()
+        row!(
+            typography::title(page.title()),
+            horizontal_space(Length::Fill),
+        )
+        .into()
     }
 
     fn is_condensed(&self) -> bool {
@@ -205,14 +215,14 @@ impl Window {
         column!(
             iced::widget::Button::new(row!(
                 icon("go-previous-symbolic", 16).style(theme::Svg::SymbolicLink),
-                text(page.title()).size(16),
+                typography::body(page.title()),
             ))
             .padding(0)
             .style(theme::Button::Link)
             .id(BTN.clone())
             .on_press(Message::from(page)),
             row!(
-                text(sub_page.title()).size(30),
+                typography::title(sub_page.title()),
                 horizontal_space(Length::Fill),
             ),
         )
@@ -231,8 +241,8 @@ impl Window {
                         .style(theme::Svg::Symbolic)
                         .into(),
                     column!(
-                        text(sub_page.title()).size(18),
-                        text(sub_page.description()).size(12),
+                        typography::heading(sub_page.title()),
+                        typography::caption(sub_page.description()),
                     )
                     .spacing(2)
                     .into(),
@@ -323,10 +333,16 @@ impl Application for Window {
             _ => None,
         });
 
-        Subscription::batch(vec![
+        let mut subscriptions = vec![
             window_break.map(|_| Message::CondensedViewToggle),
             tab_navagation.map(Message::TabNav),
-        ])
+        ];
+
+        if self.theme_transition.is_some() {
+            subscriptions.push(theme::transition_subscription().map(Message::ThemeTick));
+        }
+
+        Subscription::batch(subscriptions)
     }
 
     fn update(&mut self, message: Message) -> iced::Command<Self::Message> {
@@ -338,13 +354,24 @@ impl Application for Window {
             }
             Message::Demo(message) => match self.demo.update(message) {
                 Some(demo::Output::Debug(debug)) => self.debug = debug,
-                Some(demo::Output::ThemeChanged(theme)) => self.theme = theme,
+                Some(demo::Output::ThemeChanged(theme)) => {
+                    self.theme_transition =
+                        Some(Transition::new(&self.theme, theme, THEME_TRANSITION));
+                }
                 None => (),
             },
             Message::Desktop(message) => match self.desktop.update(message) {
                 Some(desktop::Output::Page(page)) => self.page(page),
                 None => (),
             },
+            Message::ThemeTick(now) => {
+                if let Some(transition) = &self.theme_transition {
+                    self.theme = transition.theme_at(now);
+                    if transition.is_finished_at(now) {
+                        self.theme_transition = None;
+                    }
+                }
+            }
             Message::ToggleSidebar => self.sidebar_toggled = !self.sidebar_toggled,
             Message::ToggleSidebarCondensed => {
                 self.sidebar_toggled_condensed = !self.sidebar_toggled_condensed
@@ -540,6 +567,6 @@ impl Application for Window {
     }
 
     fn theme(&self) -> Theme {
-        self.theme
+        self.theme.clone()
     }
 }
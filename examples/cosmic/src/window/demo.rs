@@ -3,9 +3,9 @@ use cosmic::{
     iced::{Alignment, Length},
     theme::{Button as ButtonTheme, Theme},
     widget::{
-        button, settings,
+        button, expander, list_column, list_item, settings,
         spin_button::{SpinButtonModel, SpinMessage},
-        toggler,
+        table, toggler, tree_view, SortOrder, TableColumn,
     },
     Element,
 };
@@ -25,20 +25,27 @@ pub enum DemoView {
     TabC,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum Message {
     ButtonPressed,
     CheckboxToggled(bool),
     Debug(bool),
     IconTheme(segmented_button::Key),
+    ListReordered(usize, usize),
+    ListSelected(usize),
     PickListSelected(&'static str),
     RowSelected(usize),
     Selection(segmented_button::Key),
     SliderChanged(f32),
     SpinButton(SpinMessage),
+    TableSelected(usize),
+    TableSorted(usize, SortOrder),
     ThemeChanged(Theme),
     TogglerToggled(bool),
+    TreeSelected(tree_view::Key),
+    TreeToggled(tree_view::Key, bool),
     ViewSwitcher(segmented_button::Key),
+    ViewSwitcherReordered(segmented_button::Key, usize),
 }
 
 pub enum Output {
@@ -49,11 +56,17 @@ pub enum Output {
 pub struct State {
     pub checkbox_value: bool,
     pub icon_theme: segmented_button::State<&'static str>,
+    pub list_items: Vec<&'static str>,
+    pub list_selected: Option<usize>,
     pub pick_list_selected: Option<&'static str>,
     pub selection: segmented_button::State<()>,
     pub slider_value: f32,
     pub spin_button: SpinButtonModel<i32>,
+    pub table_rows: Vec<(&'static str, u32)>,
+    pub table_selected: Option<usize>,
+    pub table_sort: (usize, SortOrder),
     pub toggler_value: bool,
+    pub tree: tree_view::State<u32>,
     pub view_switcher: segmented_button::State<DemoView>,
 }
 
@@ -61,10 +74,23 @@ impl Default for State {
     fn default() -> State {
         State {
             checkbox_value: false,
+            list_items: vec!["First", "Second", "Third"],
+            list_selected: None,
             pick_list_selected: Some("Option 1"),
             slider_value: 50.0,
             spin_button: SpinButtonModel::default().min(-10).max(10),
+            table_rows: vec![("Documents", 24), ("Music", 512), ("Pictures", 128)],
+            table_selected: None,
+            table_sort: (0, SortOrder::Ascending),
             toggler_value: false,
+            tree: {
+                let mut tree = tree_view::State::default();
+                for name in ["Desktop", "Documents", "Downloads"] {
+                    let key = tree.insert(None, name, 0);
+                    tree.set_expandable(key, true);
+                }
+                tree
+            },
             icon_theme: {
                 let mut icon_theme = segmented_button::State::default();
                 let key = icon_theme.insert("Pop", "Pop");
@@ -78,6 +104,8 @@ impl Default for State {
                 selection.activate(key);
                 selection.insert("Choice B", ());
                 selection.insert("Choice C", ());
+                let key = selection.insert("Choice D", ());
+                selection.set_enabled(key, false);
                 selection
             },
             view_switcher: {
@@ -85,7 +113,12 @@ impl Default for State {
                 let key = view_switcher.insert("Controls", DemoView::TabA);
                 view_switcher.activate(key);
                 view_switcher.insert("Segmented Button", DemoView::TabB);
-                view_switcher.insert("Tab C", DemoView::TabC);
+                view_switcher.insert(
+                    segmented_button::ButtonContent::new("Tab C")
+                        .icon("folder-symbolic")
+                        .badge(3),
+                    DemoView::TabC,
+                );
                 view_switcher
             },
         }
@@ -98,14 +131,57 @@ impl State {
             Message::ButtonPressed => (),
             Message::CheckboxToggled(value) => self.checkbox_value = value,
             Message::Debug(value) => return Some(Output::Debug(value)),
+            Message::ListReordered(from, to) => {
+                let item = self.list_items.remove(from);
+                self.list_items.insert(to, item);
+                self.list_selected = self.list_selected.map(|row| match row {
+                    row if row == from => to,
+                    row if from < row && row <= to => row - 1,
+                    row if to <= row && row < from => row + 1,
+                    row => row,
+                });
+            }
+            Message::ListSelected(row) => self.list_selected = Some(row),
             Message::PickListSelected(value) => self.pick_list_selected = Some(value),
             Message::RowSelected(row) => println!("Selected row {row}"),
             Message::Selection(key) => self.selection.activate(key),
             Message::SliderChanged(value) => self.slider_value = value,
             Message::SpinButton(msg) => self.spin_button.update(msg),
+            Message::TableSelected(row) => self.table_selected = Some(row),
+            Message::TableSorted(column, order) => {
+                self.table_sort = (column, order);
+                self.table_selected = None;
+                self.table_rows.sort_by(|a, b| {
+                    let ordering = match column {
+                        0 => a.0.cmp(b.0),
+                        _ => a.1.cmp(&b.1),
+                    };
+
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+            }
             Message::ThemeChanged(theme) => return Some(Output::ThemeChanged(theme)),
             Message::TogglerToggled(value) => self.toggler_value = value,
+            Message::TreeSelected(key) => self.tree.select(key),
+            Message::TreeToggled(key, expanded) => {
+                // Children are created when a folder is first expanded.
+                if expanded && self.tree.children(key).is_empty() {
+                    let depth = self.tree.data(key).copied().unwrap_or(0) + 1;
+                    for name in ["Projects", "Notes"] {
+                        let child = self.tree.insert(Some(key), name, depth);
+                        self.tree.set_expandable(child, depth < 3);
+                    }
+                }
+
+                self.tree.set_expanded(key, expanded);
+            }
             Message::ViewSwitcher(key) => self.view_switcher.activate(key),
+            Message::ViewSwitcherReordered(key, position) => {
+                self.view_switcher.reorder(key, position);
+            }
             Message::IconTheme(key) => {
                 self.icon_theme.activate(key);
                 if let Some(theme) = self.icon_theme.data(key) {
@@ -118,15 +194,19 @@ impl State {
     }
 
     pub(super) fn view<'a>(&'a self, window: &'a Window) -> Element<'a, Message> {
-        let choose_theme = [Theme::Light, Theme::Dark].iter().fold(
+        let themes = [
+            Theme::Light,
+            Theme::Dark,
+            Theme::HighContrastLight,
+            Theme::HighContrastDark,
+        ];
+        let selected_theme = themes.iter().position(|theme| *theme == window.theme);
+        let choose_theme = themes.iter().enumerate().fold(
             row![].spacing(10).align_items(Alignment::Center),
-            |row, theme| {
-                row.push(radio(
-                    format!("{:?}", theme),
-                    *theme,
-                    Some(window.theme),
-                    Message::ThemeChanged,
-                ))
+            |row, (nth, theme)| {
+                row.push(radio(format!("{:?}", theme), nth, selected_theme, |_| {
+                    Message::ThemeChanged(theme.clone())
+                }))
             },
         );
 
@@ -136,7 +216,9 @@ impl State {
         settings::view_column(vec![
             window.page_title(Page::Demo),
             horizontal_view_switcher(&self.view_switcher)
+                .global_shortcuts(true)
                 .on_activate(Message::ViewSwitcher)
+                .on_reorder(Message::ViewSwitcherReordered)
                 .into(),
             match self.view_switcher.active_data() {
                 None => panic!("no tab is active"),
@@ -223,9 +305,7 @@ impl State {
                 .padding(0)
                 .into(),
                 Some(DemoView::TabB) => settings::view_column(vec![
-                    cosmic::iced::widget::text("Selection")
-                        .font(cosmic::font::FONT_SEMIBOLD)
-                        .into(),
+                    cosmic::widget::text::heading("Selection").into(),
                     cosmic::iced::widget::text("Horizontal").into(),
                     horizontal_segmented_selection(&self.selection)
                         .on_activate(Message::Selection)
@@ -260,9 +340,7 @@ impl State {
                     .spacing(12)
                     .width(Length::Fill)
                     .into(),
-                    cosmic::iced::widget::text("View Switcher")
-                        .font(cosmic::font::FONT_SEMIBOLD)
-                        .into(),
+                    cosmic::widget::text::heading("View Switcher").into(),
                     cosmic::iced::widget::text("Horizontal").into(),
                     horizontal_view_switcher(&self.selection)
                         .on_activate(Message::Selection)
@@ -303,7 +381,57 @@ impl State {
                 Some(DemoView::TabC) => {
                     settings::view_column(vec![settings::view_section("Tab C")
                         .add(cosmic::iced::widget::text("Nothing here yet").width(Length::Fill))
+                        .add(
+                            list_item("List item")
+                                .icon("document-properties-symbolic")
+                                .subtitle("With an icon, subtitle, and trailing widget")
+                                .trailing(toggler(
+                                    None,
+                                    self.toggler_value,
+                                    Message::TogglerToggled,
+                                )),
+                        )
+                        .add(
+                            expander(
+                                "Expander",
+                                cosmic::iced::widget::text("Revealed when expanded"),
+                            )
+                            .subtitle("Click the header to expand"),
+                        )
                         .into()])
+                    .push(
+                        self.list_items
+                            .iter()
+                            .fold(list_column(), |column, item| column.add(list_item(*item)))
+                            .selected(self.list_selected)
+                            .on_select(Message::ListSelected)
+                            .on_reorder(Message::ListReordered),
+                    )
+                    .push(
+                        table(&self.table_rows)
+                            .column(
+                                TableColumn::text("Folder", |(name, _): &(&str, u32)| {
+                                    name.to_string()
+                                })
+                                .sortable(true),
+                            )
+                            .column(
+                                TableColumn::text("Size", |(_, size): &(&str, u32)| {
+                                    format!("{size} MB")
+                                })
+                                .sortable(true),
+                            )
+                            .striped(true)
+                            .sort(self.table_sort.0, self.table_sort.1)
+                            .on_sort(Message::TableSorted)
+                            .selected(self.table_selected)
+                            .on_select(Message::TableSelected),
+                    )
+                    .push(
+                        tree_view(&self.tree)
+                            .on_select(Message::TreeSelected)
+                            .on_toggle(Message::TreeToggled),
+                    )
                     .padding(0)
                     .into()
                 }
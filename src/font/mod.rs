@@ -0,0 +1,145 @@
+// Copyright 2022 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Fonts of the interface, which are discovered from the system through fontconfig.
+//!
+//! Discovery is enabled by the `fontconfig` feature. If it is disabled, or fontconfig is
+//! unavailable, the bundled Fira fonts are used instead. The bundled fonts are embedded
+//! by the `embedded-fonts` feature, and without it, text is drawn with the default font
+//! of the renderer.
+
+mod system;
+
+use self::system::SystemFont;
+
+pub use iced::Font;
+
+#[cfg(feature = "embedded-fonts")]
+pub const FONT: Font = Font::External {
+    name: "Fira Sans Regular",
+    bytes: include_bytes!("../../res/Fira/FiraSans-Regular.otf"),
+};
+
+#[cfg(feature = "embedded-fonts")]
+pub const FONT_LIGHT: Font = Font::External {
+    name: "Fira Sans Light",
+    bytes: include_bytes!("../../res/Fira/FiraSans-Light.otf"),
+};
+
+#[cfg(feature = "embedded-fonts")]
+pub const FONT_SEMIBOLD: Font = Font::External {
+    name: "Fira Sans SemiBold",
+    bytes: include_bytes!("../../res/Fira/FiraSans-SemiBold.otf"),
+};
+
+#[cfg(feature = "embedded-fonts")]
+pub const FONT_MONO: Font = Font::External {
+    name: "Fira Mono Regular",
+    bytes: include_bytes!("../../res/Fira/FiraMono-Regular.otf"),
+};
+
+#[cfg(not(feature = "embedded-fonts"))]
+pub const FONT: Font = Font::Default;
+
+#[cfg(not(feature = "embedded-fonts"))]
+pub const FONT_LIGHT: Font = Font::Default;
+
+#[cfg(not(feature = "embedded-fonts"))]
+pub const FONT_SEMIBOLD: Font = Font::Default;
+
+#[cfg(not(feature = "embedded-fonts"))]
+pub const FONT_MONO: Font = Font::Default;
+
+static REGULAR: SystemFont = SystemFont::new("sans-serif", None);
+static LIGHT: SystemFont = SystemFont::new("sans-serif", Some("Light"));
+static SEMIBOLD: SystemFont = SystemFont::new("sans-serif", Some("SemiBold"));
+static MONOSPACE: SystemFont = SystemFont::new("monospace", None);
+
+/// The interface font configured by the desktop, or the bundled [`FONT`].
+#[must_use]
+pub fn regular() -> Font {
+    REGULAR.font().unwrap_or(FONT)
+}
+
+/// The light weight of the interface font, or the bundled [`FONT_LIGHT`].
+#[must_use]
+pub fn light() -> Font {
+    LIGHT.font().unwrap_or(FONT_LIGHT)
+}
+
+/// The semibold weight of the interface font, or the bundled [`FONT_SEMIBOLD`].
+#[must_use]
+pub fn semibold() -> Font {
+    SEMIBOLD.font().unwrap_or(FONT_SEMIBOLD)
+}
+
+/// The monospace font configured by the desktop, or the bundled [`FONT_MONO`].
+#[must_use]
+pub fn monospace() -> Font {
+    MONOSPACE.font().unwrap_or(FONT_MONO)
+}
+
+/// A font paired with the size that it is displayed at, in pixels.
+///
+/// Text widgets do not support line heights, so lines are spaced by the font.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Typography {
+    pub font: Font,
+    pub size: u16,
+}
+
+impl Typography {
+    /// Large text for hero headers and empty states.
+    #[must_use]
+    pub fn display() -> Self {
+        Self {
+            font: light(),
+            size: 40,
+        }
+    }
+
+    /// Titles of pages.
+    #[must_use]
+    pub fn title() -> Self {
+        Self {
+            font: regular(),
+            size: 30,
+        }
+    }
+
+    /// Headers of sections, and the titles of header bars.
+    #[must_use]
+    pub fn heading() -> Self {
+        Self {
+            font: semibold(),
+            size: 18,
+        }
+    }
+
+    /// Text for labels and paragraphs.
+    #[must_use]
+    pub fn body() -> Self {
+        Self {
+            font: regular(),
+            size: 16,
+        }
+    }
+
+    /// Small text for descriptions and annotations.
+    #[must_use]
+    pub fn caption() -> Self {
+        Self {
+            font: regular(),
+            size: 14,
+        }
+    }
+
+    /// Fixed-width text for code and terminal output.
+    #[must_use]
+    pub fn monospace() -> Self {
+        Self {
+            font: monospace(),
+            size: 16,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Discovers the fonts configured by the desktop through fontconfig.
+
+use super::Font;
+use once_cell::sync::OnceCell;
+
+/// A font configured by the desktop, which is discovered and loaded when it is first used.
+pub(super) struct SystemFont {
+    family: &'static str,
+    style: Option<&'static str>,
+    /// The name and contents of the font, which are kept for the lifetime of the application.
+    loaded: OnceCell<Option<(String, Vec<u8>)>>,
+}
+
+impl SystemFont {
+    /// A font which best matches a fontconfig family, such as `monospace`, and style.
+    pub(super) const fn new(family: &'static str, style: Option<&'static str>) -> Self {
+        Self {
+            family,
+            style,
+            loaded: OnceCell::new(),
+        }
+    }
+
+    /// The matching font, or `None` if fontconfig or the font could not be loaded.
+    pub(super) fn font(&'static self) -> Option<Font> {
+        let (name, bytes) = self
+            .loaded
+            .get_or_init(|| load(self.family, self.style))
+            .as_ref()?;
+
+        Some(Font::External {
+            name: name.as_str(),
+            bytes: bytes.as_slice(),
+        })
+    }
+}
+
+/// Reads the system font which best matches a family and style.
+///
+/// Returns `None` if the matching font is a member of a font collection, since only the
+/// first font of a collection can be loaded.
+#[cfg(feature = "fontconfig")]
+fn load(family: &str, style: Option<&str>) -> Option<(String, Vec<u8>)> {
+    let font = fontconfig::Fontconfig::new()?.find(family, style)?;
+
+    if font.index.unwrap_or(0) != 0 {
+        return None;
+    }
+
+    // The renderer caches fonts by name, which is the full name of the font, and so
+    // distinct for each style.
+    match std::fs::read(&font.path) {
+        Ok(bytes) => Some((font.name, bytes)),
+        Err(why) => {
+            eprintln!("failed to read font from {}: {}", font.path.display(), why);
+            None
+        }
+    }
+}
+
+#[cfg(not(feature = "fontconfig"))]
+fn load(_family: &str, _style: Option<&str>) -> Option<(String, Vec<u8>)> {
+    None
+}
@@ -21,7 +21,7 @@ pub fn set_default_icon_theme(name: impl Into<String>) {
 #[must_use]
 pub fn settings<Flags: Default>() -> iced::Settings<Flags> {
     iced::Settings {
-        default_font: match font::FONT {
+        default_font: match font::Typography::body().font {
             iced::Font::Default => None,
             iced::Font::External { bytes, .. } => Some(bytes),
         },
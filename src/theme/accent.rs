@@ -0,0 +1,123 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Generates themes from a single accent color.
+
+use super::contrast::{ratio as contrast, required, WCAG_AA};
+use super::palette::{from_hsl, is_dark, mix, to_hsl};
+use super::{CosmicColor, CosmicComponent, CosmicTheme, CustomTheme, Theme};
+use iced_core::Color;
+use std::sync::Arc;
+
+/// Amount that the lightness of the seed color is adjusted by per step.
+const LIGHTNESS_STEP: f32 = 0.02;
+
+impl Theme {
+    /// Derives a theme from this theme with an accent generated from a seed color.
+    ///
+    /// Use [`Theme::Light`] or [`Theme::Dark`] as the base for a light or dark theme. The
+    /// high contrast themes remain high contrast, with an accent readable at WCAG level AAA.
+    #[must_use]
+    pub fn with_accent(&self, seed: Color) -> Self {
+        let high_contrast = self.is_high_contrast();
+        let mut cosmic = with_accent(self.cosmic(), seed, required(self));
+
+        // Backgrounds are separated again from the new accent, which is drawn on them.
+        if high_contrast {
+            cosmic = super::high_contrast::theme(&cosmic);
+        }
+
+        Self::Custom(Arc::new(CustomTheme::derived(
+            cosmic,
+            self.density(),
+            high_contrast,
+        )))
+    }
+}
+
+/// Derives a COSMIC theme from `base` with an accent generated from a seed color.
+#[must_use]
+pub fn accent_theme(base: &CosmicTheme, seed: Color) -> CosmicTheme {
+    with_accent(base, seed, WCAG_AA)
+}
+
+/// Derives a COSMIC theme with an accent which has the given minimum contrast ratio.
+fn with_accent(base: &CosmicTheme, seed: Color, min_contrast: f32) -> CosmicTheme {
+    let mut theme = base.clone();
+    let accent = component(seed, theme.bg_color().into(), min_contrast);
+
+    for component in [&mut theme.primary.component, &mut theme.secondary.component] {
+        component.focus = accent.base;
+        component.selected_text = accent.base;
+    }
+
+    theme.accent = accent;
+    theme
+}
+
+/// Generates every state of an accent component from a seed color.
+///
+/// The lightness of the seed is adjusted until it is readable on the `background`,
+/// and text drawn on it is readable, with a contrast ratio of at least 4.5:1.
+#[must_use]
+pub fn accent_component(seed: Color, background: Color) -> CosmicComponent {
+    component(seed, background, WCAG_AA)
+}
+
+/// Generates every state of a component with the given minimum contrast ratio.
+pub(super) fn component(seed: Color, background: Color, min_contrast: f32) -> CosmicComponent {
+    let base = readable_accent(seed, background, min_contrast);
+    let on = text_color(base);
+
+    CosmicComponent {
+        base: cosmic_color(base, 1.0),
+        hover: cosmic_color(mix(base, on, 0.1), 1.0),
+        pressed: cosmic_color(mix(base, on, 0.2), 1.0),
+        selected: cosmic_color(mix(base, on, 0.1), 1.0),
+        selected_text: cosmic_color(on, 1.0),
+        focus: cosmic_color(base, 1.0),
+        divider: cosmic_color(on, 0.2),
+        on: cosmic_color(on, 1.0),
+        disabled: cosmic_color(base, 0.5),
+        on_disabled: cosmic_color(on, 0.5),
+    }
+}
+
+/// Lightens or darkens the seed until it has enough contrast with its text and background.
+fn readable_accent(seed: Color, background: Color, min_contrast: f32) -> Color {
+    let lighten = is_dark(background);
+    let mut hsl = to_hsl(seed);
+
+    loop {
+        let color = from_hsl(hsl);
+
+        if contrast(color, background) >= min_contrast
+            && contrast(color, text_color(color)) >= min_contrast
+        {
+            return color;
+        }
+
+        if lighten && hsl.lightness >= 1.0 || !lighten && hsl.lightness <= 0.0 {
+            return color;
+        }
+
+        hsl.lightness = if lighten {
+            (hsl.lightness + LIGHTNESS_STEP).min(1.0)
+        } else {
+            (hsl.lightness - LIGHTNESS_STEP).max(0.0)
+        };
+    }
+}
+
+/// Black or white, whichever has the higher contrast with the background.
+pub(super) fn text_color(background: Color) -> Color {
+    if contrast(background, Color::WHITE) >= contrast(background, Color::BLACK) {
+        Color::WHITE
+    } else {
+        Color::BLACK
+    }
+}
+
+pub(super) fn cosmic_color(color: Color, alpha: f32) -> CosmicColor {
+    CosmicColor::new(color.r, color.g, color.b, alpha)
+}
@@ -0,0 +1,190 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Audits the contrast of text and icons drawn by the styles of a [`Theme`].
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use cosmic::theme::{contrast, Theme};
+//!
+//! for issue in contrast::audit(&Theme::Dark, contrast::WCAG_AA) {
+//!     eprintln!("{}", issue);
+//! }
+//! ```
+
+use super::palette::composite;
+use super::{Button, SegmentedButton, Svg, Text, Theme};
+use crate::widget::segmented_button::{self, StyleSheet as _};
+use iced_core::{Background, Color};
+use iced_style::{button::StyleSheet as _, svg, text};
+use palette::{RelativeContrast, Srgb};
+use std::fmt;
+
+/// Minimum contrast ratio of text required by WCAG level AA.
+pub const WCAG_AA: f32 = 4.5;
+
+/// Minimum contrast ratio of text required by WCAG level AAA.
+pub const WCAG_AAA: f32 = 7.0;
+
+/// The minimum contrast ratio that the styles of a theme are expected to meet.
+///
+/// High contrast themes are held to WCAG level AAA, and other themes to level AA.
+#[must_use]
+pub fn required(theme: &Theme) -> f32 {
+    if theme.is_high_contrast() {
+        WCAG_AAA
+    } else {
+        WCAG_AA
+    }
+}
+
+/// The lowest contrast ratio of any style of the theme.
+#[must_use]
+pub fn minimum(theme: &Theme) -> f32 {
+    audit(theme, f32::INFINITY)
+        .iter()
+        .fold(f32::INFINITY, |minimum, issue| minimum.min(issue.ratio))
+}
+
+/// The contrast ratio between two colors, from 1:1 to 21:1.
+#[must_use]
+pub fn ratio(a: Color, b: Color) -> f32 {
+    Srgb::from(a).get_contrast_ratio(&Srgb::from(b))
+}
+
+/// A foreground and background pair with too little contrast.
+#[derive(Clone, Copy, Debug)]
+pub struct Issue {
+    /// The style which draws the pair, such as `Button::Primary`.
+    pub style: &'static str,
+    /// The state of the style, such as `hovered`.
+    pub state: &'static str,
+    /// The color of the text or icon.
+    pub foreground: Color,
+    /// The color that the text or icon is drawn on.
+    pub background: Color,
+    /// The contrast ratio between the foreground and background.
+    pub ratio: f32,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) has a contrast ratio of {:.2}:1",
+            self.style, self.state, self.ratio
+        )
+    }
+}
+
+const BUTTONS: [(&str, Button); 9] = [
+    ("Button::Deactivated", Button::Deactivated),
+    ("Button::Destructive", Button::Destructive),
+    ("Button::Positive", Button::Positive),
+    ("Button::Primary", Button::Primary),
+    ("Button::Secondary", Button::Secondary),
+    ("Button::Text", Button::Text),
+    ("Button::Link", Button::Link),
+    ("Button::LinkActive", Button::LinkActive),
+    ("Button::Transparent", Button::Transparent),
+];
+
+const SEGMENTED_BUTTONS: [(&str, SegmentedButton); 2] = [
+    (
+        "SegmentedButton::ViewSwitcher",
+        SegmentedButton::ViewSwitcher,
+    ),
+    ("SegmentedButton::Selection", SegmentedButton::Selection),
+];
+
+/// Reports every style variant of the theme whose contrast is below the `threshold`.
+///
+/// Foregrounds which are fully transparent inherit their color from their content, and
+/// are skipped. `Custom` variants are not audited.
+#[must_use]
+pub fn audit(theme: &Theme, threshold: f32) -> Vec<Issue> {
+    let cosmic = theme.cosmic();
+    let window: Color = cosmic.bg_color().into();
+    let mut issues = Vec::new();
+
+    let mut check = |style: &'static str,
+                     state: &'static str,
+                     foreground: Color,
+                     background: Option<Background>| {
+        if foreground.a == 0.0 {
+            return;
+        }
+
+        let background = match background {
+            Some(Background::Color(color)) => composite(color, window),
+            _ => window,
+        };
+
+        let foreground = composite(foreground, background);
+        let ratio = ratio(foreground, background);
+
+        if ratio < threshold {
+            issues.push(Issue {
+                style,
+                state,
+                foreground,
+                background,
+                ratio,
+            });
+        }
+    };
+
+    for (name, style) in BUTTONS {
+        let active = theme.active(&style);
+        check(name, "active", active.text_color, active.background);
+
+        let hovered = theme.hovered(&style);
+        check(name, "hovered", hovered.text_color, hovered.background);
+    }
+
+    if let Some(color) = text::StyleSheet::appearance(theme, Text::Accent).color {
+        check("Text::Accent", "default", color, None);
+    }
+
+    let accent = Some(Background::Color(cosmic.accent.base.into()));
+    for (name, style, background) in [
+        ("Svg::Symbolic", Svg::Symbolic, None),
+        ("Svg::SymbolicActive", Svg::SymbolicActive, None),
+        ("Svg::SymbolicPrimary", Svg::SymbolicPrimary, accent),
+        ("Svg::SymbolicLink", Svg::SymbolicLink, None),
+    ] {
+        if let Some(color) = svg::StyleSheet::appearance(theme, &style).color {
+            check(name, "default", color, background);
+        }
+    }
+
+    for (name, style) in SEGMENTED_BUTTONS {
+        for appearance in [theme.horizontal(&style), theme.vertical(&style)] {
+            let segmented_button::Appearance {
+                background,
+                active,
+                inactive,
+                hover,
+                badge_background,
+                badge_text_color,
+                ..
+            } = appearance;
+
+            if badge_background.is_some() {
+                check(name, "badge", badge_text_color, badge_background);
+            }
+
+            for (state, status) in [("active", active), ("inactive", inactive), ("hover", hover)] {
+                check(
+                    name,
+                    state,
+                    status.text_color,
+                    status.background.or(background),
+                );
+            }
+        }
+    }
+
+    issues
+}
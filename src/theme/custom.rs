@@ -0,0 +1,121 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Themes which are loaded or generated at runtime.
+
+use super::palette::Extended;
+use super::{CosmicTheme, CosmicThemeCss, Density, Theme};
+use std::{fmt, io, path::Path, path::PathBuf};
+
+/// A COSMIC theme which was loaded or generated at runtime.
+#[derive(Debug)]
+pub struct CustomTheme {
+    cosmic: CosmicTheme,
+    extended: Extended,
+    density: Density,
+    high_contrast: bool,
+}
+
+impl CustomTheme {
+    /// Derives the extended palette of a custom theme from a COSMIC theme.
+    #[must_use]
+    pub fn new(cosmic: CosmicTheme) -> Self {
+        Self::derived(cosmic, Density::default(), false)
+    }
+
+    /// A custom theme which keeps the density and contrast of the theme it was derived from.
+    pub(super) fn derived(cosmic: CosmicTheme, density: Density, high_contrast: bool) -> Self {
+        Self {
+            extended: Extended::from_cosmic(&cosmic),
+            cosmic,
+            density,
+            high_contrast,
+        }
+    }
+
+    /// The density of the spacing and sizing of widgets.
+    #[must_use]
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    /// Whether styles should draw stronger borders and focus rings.
+    #[must_use]
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast
+    }
+
+    /// The COSMIC theme that this theme was created from.
+    #[must_use]
+    pub fn cosmic(&self) -> &CosmicTheme {
+        &self.cosmic
+    }
+
+    /// The extended palette derived from the COSMIC theme.
+    #[must_use]
+    pub fn extended_palette(&self) -> &Extended {
+        &self.extended
+    }
+}
+
+/// An error which occurred while loading a custom theme.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The theme file could not be read.
+    Io(PathBuf, io::Error),
+    /// The theme is not a valid RON-encoded COSMIC theme.
+    Parse(ron::error::SpannedError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(path, why) => {
+                write!(f, "failed to read theme from {}: {}", path.display(), why)
+            }
+            LoadError::Parse(why) => write!(f, "failed to parse theme: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(_, why) => Some(why),
+            LoadError::Parse(why) => Some(why),
+        }
+    }
+}
+
+impl Theme {
+    /// Parses a custom theme from a RON-encoded COSMIC theme.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError::Parse`] if the input is not a valid COSMIC theme.
+    pub fn from_ron(ron: &str) -> Result<Self, LoadError> {
+        let theme: CosmicThemeCss = ron::from_str(ron).map_err(LoadError::Parse)?;
+        Ok(Self::custom(theme.into_srgba()))
+    }
+
+    /// Loads a custom theme from a RON-encoded COSMIC theme file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be read or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        let ron =
+            std::fs::read_to_string(path).map_err(|why| LoadError::Io(path.to_path_buf(), why))?;
+        Self::from_ron(&ron)
+    }
+
+    /// Loads a custom theme from a file, using the `fallback` theme on failure.
+    #[must_use]
+    pub fn from_file_or(path: impl AsRef<Path>, fallback: Self) -> Self {
+        Self::from_file(path).unwrap_or_else(|why| {
+            eprintln!("{}, falling back to {:?} theme", why, fallback);
+            fallback
+        })
+    }
+}
@@ -3,34 +3,34 @@
 
 use iced_core::{Background, Color};
 
-/// The appearance of a [`Expander`](crate::native::expander::Expander).
+/// The appearance of a [`Expander`](crate::widget::Expander).
 #[derive(Clone, Copy, Debug)]
 pub struct Appearance {
-    /// The background of the [`Expander`](crate::native::expander::Expander).
+    /// The background of the [`Expander`](crate::widget::Expander).
     pub background: Background,
 
-    /// The border radius of the [`Expander`](crate::native::expander::Expander).
+    /// The border radius of the [`Expander`](crate::widget::Expander).
     pub border_radius: f32,
 
-    /// The border width of the [`Expander`](crate::native::expander::Expander).
+    /// The border width of the [`Expander`](crate::widget::Expander).
     pub border_width: f32,
 
-    /// The border color of the [`Expander`](crate::native::expander::Expander).
+    /// The border color of the [`Expander`](crate::widget::Expander).
     pub border_color: Color,
 
-    /// The background of the head of the [`Expander`](crate::native::expander::Expander).
+    /// The background of the head of the [`Expander`](crate::widget::Expander).
     pub head_background: Background,
 
-    /// The text color of the head of the [`Expander`](crate::native::expander::Expander).
+    /// The text color of the head of the [`Expander`](crate::widget::Expander).
     pub head_text_color: Color,
 
-    /// The background of the body of the [`Expander`](crate::native::expander::Expander).
+    /// The background of the body of the [`Expander`](crate::widget::Expander).
     pub body_background: Background,
 
-    /// The text color of the body of the [`Expander`](crate::native::expander::Expander).
+    /// The text color of the body of the [`Expander`](crate::widget::Expander).
     pub body_text_color: Color,
 
-    /// The color of the close icon of the [`Expander`](crate::native::expander::Expander).
+    /// The color of the close icon of the [`Expander`](crate::widget::Expander).
     pub toggle_color: Color,
 }
 
@@ -0,0 +1,125 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Derives high contrast variants of COSMIC themes.
+
+use super::accent::{component, cosmic_color, text_color};
+use super::contrast::{ratio as contrast, WCAG_AAA};
+use super::palette::{composite, mix};
+use super::{CosmicColor, CosmicComponent, CosmicTheme};
+use iced_core::Color;
+
+/// Amount that a background is moved towards black or white per step.
+const SEPARATION_STEP: f32 = 0.05;
+
+/// Derives a high contrast theme with opaque backgrounds and black or white text.
+///
+/// Every background is moved away from the text drawn on it, towards black in dark
+/// themes and white in light themes, until text and accents on it have a contrast ratio
+/// of at least 7:1.
+pub(super) fn theme(base: &CosmicTheme) -> CosmicTheme {
+    let mut theme = base.clone();
+    let background: Color = theme.bg_color().into();
+    let on = text_color(background);
+    let extreme = text_color(on);
+
+    // Accents are made readable on the extreme, which every background may fall back to.
+    theme.accent = component(theme.accent.base.into(), extreme, WCAG_AAA);
+    theme.success = component(theme.success.base.into(), extreme, WCAG_AAA);
+    theme.destructive = component(theme.destructive.base.into(), extreme, WCAG_AAA);
+
+    for semantic in [
+        &mut theme.accent,
+        &mut theme.success,
+        &mut theme.destructive,
+    ] {
+        separate_states(semantic);
+    }
+
+    let accent: Color = theme.accent.base.into();
+
+    for container in [
+        &mut theme.background,
+        &mut theme.primary,
+        &mut theme.secondary,
+    ] {
+        let base = separate(opaque(container.base, background), extreme, &[on, accent]);
+
+        container.base = cosmic_color(base, 1.0);
+        container.on = cosmic_color(on, 1.0);
+        container.divider = cosmic_color(on, 1.0);
+        strengthen(&mut container.component, base, on, extreme, accent);
+    }
+
+    theme
+}
+
+/// Flattens a component onto its container, and replaces its text with black or white.
+///
+/// Accents are also drawn on components, such as the text of active segmented buttons.
+fn strengthen(
+    component: &mut CosmicComponent,
+    container: Color,
+    on: Color,
+    extreme: Color,
+    accent: Color,
+) {
+    let base = separate(opaque(component.base, container), extreme, &[on, accent]);
+
+    for color in [
+        &mut component.base,
+        &mut component.hover,
+        &mut component.pressed,
+        &mut component.selected,
+        &mut component.disabled,
+    ] {
+        let flattened = separate(opaque(*color, container), extreme, &[on, accent]);
+        *color = cosmic_color(flattened, 1.0);
+    }
+
+    // The divider of a component is drawn behind text, such as an active link.
+    component.divider = component.selected;
+    component.on = cosmic_color(on, 1.0);
+    component.selected_text = cosmic_color(on, 1.0);
+    component.on_disabled = cosmic_color(mix(base, on, 0.6), 1.0);
+}
+
+/// Keeps the hovered, pressed, and selected states of an accent readable with its text.
+fn separate_states(component: &mut CosmicComponent) {
+    let on: Color = component.on.into();
+    let extreme = text_color(on);
+
+    for color in [
+        &mut component.hover,
+        &mut component.pressed,
+        &mut component.selected,
+    ] {
+        *color = cosmic_color(separate((*color).into(), extreme, &[on]), 1.0);
+    }
+}
+
+/// Moves a background towards `extreme` until every foreground has enough contrast with it.
+///
+/// The foregrounds must be readable on the extreme, which is returned if nothing closer is.
+fn separate(background: Color, extreme: Color, foregrounds: &[Color]) -> Color {
+    let mut factor = 0.0;
+
+    loop {
+        let color = mix(background, extreme, factor);
+
+        if factor >= 1.0
+            || foregrounds
+                .iter()
+                .all(|&foreground| contrast(color, foreground) >= WCAG_AAA)
+        {
+            return color;
+        }
+
+        factor = (factor + SEPARATION_STEP).min(1.0);
+    }
+}
+
+/// Composites a translucent COSMIC color over an opaque background.
+fn opaque(color: CosmicColor, background: Color) -> Color {
+    composite(color.into(), background)
+}
@@ -1,15 +1,30 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
+mod accent;
+pub mod contrast;
+mod custom;
 pub mod expander;
+mod high_contrast;
 pub mod palette;
 mod segmented_button;
+mod subscription;
+pub mod tokens;
+mod transition;
 
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Arc;
 
+pub use self::accent::{accent_component, accent_theme};
+pub use self::custom::{CustomTheme, LoadError};
 pub use self::palette::Palette;
 pub use self::segmented_button::SegmentedButton;
+pub use self::subscription::theme_subscription;
+pub use self::tokens::{Density, Tokens};
+#[cfg(feature = "tokio")]
+pub use self::transition::transition_subscription;
+pub use self::transition::{interpolate, Transition};
 
 use cosmic_theme::Component;
 use iced_core::BorderRadius;
@@ -32,14 +47,16 @@ use iced_style::toggler;
 
 use iced_core::{Background, Color};
 
-type CosmicColor = ::palette::rgb::Srgba;
-type CosmicComponent = cosmic_theme::Component<CosmicColor>;
-type CosmicTheme = cosmic_theme::Theme<CosmicColor>;
-type CosmicThemeCss = cosmic_theme::Theme<cosmic_theme::util::CssColor>;
+pub type CosmicColor = ::palette::rgb::Srgba;
+pub type CosmicComponent = cosmic_theme::Component<CosmicColor>;
+pub type CosmicTheme = cosmic_theme::Theme<CosmicColor>;
+pub type CosmicThemeCss = cosmic_theme::Theme<cosmic_theme::util::CssColor>;
 
 lazy_static::lazy_static! {
     pub static ref COSMIC_DARK: CosmicTheme = CosmicThemeCss::dark_default().into_srgba();
     pub static ref COSMIC_LIGHT: CosmicTheme = CosmicThemeCss::light_default().into_srgba();
+    pub static ref COSMIC_HC_DARK: CosmicTheme = high_contrast::theme(&COSMIC_DARK);
+    pub static ref COSMIC_HC_LIGHT: CosmicTheme = high_contrast::theme(&COSMIC_LIGHT);
     pub static ref TRANSPARENT_COMPONENT: Component<CosmicColor> = Component {
         base: CosmicColor::new(0.0, 0.0, 0.0, 0.0),
         hover: CosmicColor::new(0.0, 0.0, 0.0, 0.0),
@@ -54,27 +71,39 @@ lazy_static::lazy_static! {
     };
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone)]
 pub enum Theme {
     Light,
     Dark,
+    /// A light theme with stronger borders and WCAG AAA text contrast.
+    HighContrastLight,
+    /// A dark theme with stronger borders and WCAG AAA text contrast.
+    HighContrastDark,
+    /// A theme which was loaded or generated at runtime.
+    Custom(Arc<CustomTheme>),
 }
 
 impl Theme {
+    /// Creates a custom theme from a COSMIC theme.
     #[must_use]
-    pub fn cosmic(self) -> &'static CosmicTheme {
+    pub fn custom(theme: CosmicTheme) -> Self {
+        Self::Custom(Arc::new(CustomTheme::new(theme)))
+    }
+
+    #[must_use]
+    pub fn cosmic(&self) -> &CosmicTheme {
         match self {
             Self::Dark => &COSMIC_DARK,
             Self::Light => &COSMIC_LIGHT,
+            Self::HighContrastDark => &COSMIC_HC_DARK,
+            Self::HighContrastLight => &COSMIC_HC_LIGHT,
+            Self::Custom(theme) => theme.cosmic(),
         }
     }
 
     #[must_use]
-    pub fn palette(self) -> Palette {
-        match self {
-            Self::Dark => Palette::DARK,
-            Self::Light => Palette::LIGHT,
-        }
+    pub fn palette(&self) -> Palette {
+        Palette::from_cosmic(self.cosmic())
     }
 
     #[must_use]
@@ -82,6 +111,76 @@ impl Theme {
         match self {
             Self::Dark => &self::palette::EXTENDED_DARK,
             Self::Light => &self::palette::EXTENDED_LIGHT,
+            Self::HighContrastDark => &self::palette::EXTENDED_HC_DARK,
+            Self::HighContrastLight => &self::palette::EXTENDED_HC_LIGHT,
+            Self::Custom(theme) => theme.extended_palette(),
+        }
+    }
+
+    /// Whether the theme has a dark background.
+    #[must_use]
+    pub fn is_dark(&self) -> bool {
+        match self {
+            Self::Dark | Self::HighContrastDark => true,
+            Self::Light | Self::HighContrastLight => false,
+            Self::Custom(theme) => self::palette::is_dark(theme.cosmic().bg_color().into()),
+        }
+    }
+
+    /// The density of the spacing and sizing of widgets, which is standard unless the theme
+    /// was created with [`Theme::with_density`].
+    #[must_use]
+    pub fn density(&self) -> Density {
+        match self {
+            Self::Custom(theme) => theme.density(),
+            _ => Density::default(),
+        }
+    }
+
+    /// Derives a theme from this theme with the spacing and sizing of another density.
+    #[must_use]
+    pub fn with_density(&self, density: Density) -> Self {
+        Self::Custom(Arc::new(CustomTheme::derived(
+            self.cosmic().clone(),
+            density,
+            self.is_high_contrast(),
+        )))
+    }
+
+    /// The spacing, radius, and size tokens of the density of this theme.
+    #[must_use]
+    pub fn tokens(&self) -> Tokens {
+        self.density().tokens()
+    }
+
+    /// Whether styles should draw stronger borders and focus rings.
+    #[must_use]
+    pub fn is_high_contrast(&self) -> bool {
+        match self {
+            Self::HighContrastDark | Self::HighContrastLight => true,
+            Self::Dark | Self::Light => false,
+            Self::Custom(theme) => theme.is_high_contrast(),
+        }
+    }
+}
+
+impl PartialEq for Theme {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Theme {}
+
+impl Hash for Theme {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        if let Self::Custom(theme) = self {
+            Arc::as_ptr(theme).hash(state);
         }
     }
 }
@@ -115,7 +214,7 @@ impl application::StyleSheet for Theme {
                 background_color: cosmic.bg_color().into(),
                 text_color: cosmic.on_bg_color().into(),
             },
-            Application::Custom(f) => f(*self),
+            Application::Custom(f) => f(self.clone()),
         }
     }
 }
@@ -146,10 +245,26 @@ impl Default for Button {
     }
 }
 
+/// Hashes the address of the function of a custom style, which identifies the style.
+fn hash_fn_ptr<H: Hasher>(address: usize, state: &mut H) {
+    address.hash(state);
+}
+
+impl Hash for Button {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        if let Button::Custom { active, hover } = self {
+            hash_fn_ptr(*active as usize, state);
+            hash_fn_ptr(*hover as usize, state);
+        }
+    }
+}
+
 impl Button {
     #[allow(clippy::trivially_copy_pass_by_ref)]
     #[allow(clippy::match_same_arms)]
-    fn cosmic(&self, theme: &Theme) -> &'static CosmicComponent {
+    fn cosmic<'a>(&self, theme: &'a Theme) -> &'a CosmicComponent {
         let cosmic = theme.cosmic();
         match self {
             Button::Primary => &cosmic.accent,
@@ -179,7 +294,7 @@ impl button::StyleSheet for Theme {
         button::Appearance {
             border_radius: match style {
                 Button::Link => BorderRadius::from(0.0),
-                _ => BorderRadius::from(24.0),
+                _ => BorderRadius::from(self.tokens().radius.round),
             },
             background: match style {
                 Button::Link | Button::Text => None,
@@ -191,6 +306,12 @@ impl button::StyleSheet for Theme {
                 Button::LinkActive => cosmic.selected_text.into(),
                 _ => cosmic.on.into(),
             },
+            border_width: if self.is_high_contrast() && !matches!(style, Button::Link) {
+                1.0
+            } else {
+                0.0
+            },
+            border_color: self.extended_palette().background.base.text,
             ..button::Appearance::default()
         }
     }
@@ -221,12 +342,20 @@ impl button::StyleSheet for Theme {
         let active = self.active(style);
         let cosmic = style.cosmic(self);
 
+        let (border_width, border_color) = if self.is_high_contrast() {
+            (3.0, self.cosmic().accent.focus.into())
+        } else {
+            (active.border_width, active.border_color)
+        };
+
         button::Appearance {
             background: match style {
                 Button::Link => None,
                 Button::LinkActive => Some(Background::Color(cosmic.divider.into())),
                 _ => Some(Background::Color(cosmic.hover.into())),
             },
+            border_width,
+            border_color,
             ..active
         }
     }
@@ -261,24 +390,28 @@ impl checkbox::StyleSheet for Theme {
                 palette.background.base,
                 palette.primary.strong,
                 is_checked,
+                self,
             ),
             Checkbox::Secondary => checkbox_appearance(
                 palette.background.base.text,
                 palette.background.base,
                 palette.background.base,
                 is_checked,
+                self,
             ),
             Checkbox::Success => checkbox_appearance(
                 palette.success.base.text,
                 palette.background.base,
                 palette.success.base,
                 is_checked,
+                self,
             ),
             Checkbox::Danger => checkbox_appearance(
                 palette.danger.base.text,
                 palette.background.base,
                 palette.danger.base,
                 is_checked,
+                self,
             ),
         }
     }
@@ -292,24 +425,28 @@ impl checkbox::StyleSheet for Theme {
                 palette.background.weak,
                 palette.primary.base,
                 is_checked,
+                self,
             ),
             Checkbox::Secondary => checkbox_appearance(
                 palette.background.base.text,
                 palette.background.weak,
                 palette.background.base,
                 is_checked,
+                self,
             ),
             Checkbox::Success => checkbox_appearance(
                 palette.success.base.text,
                 palette.background.weak,
                 palette.success.base,
                 is_checked,
+                self,
             ),
             Checkbox::Danger => checkbox_appearance(
                 palette.danger.base.text,
                 palette.background.weak,
                 palette.danger.base,
                 is_checked,
+                self,
             ),
         }
     }
@@ -320,13 +457,24 @@ fn checkbox_appearance(
     base: palette::Pair,
     accent: palette::Pair,
     is_checked: bool,
+    theme: &Theme,
 ) -> checkbox::Appearance {
+    let high_contrast = theme.is_high_contrast();
+
     checkbox::Appearance {
         background: Background::Color(if is_checked { accent.color } else { base.color }),
         checkmark_color,
-        border_radius: 4.0,
-        border_width: if is_checked { 0.0 } else { 1.0 },
-        border_color: accent.color,
+        border_radius: theme.tokens().radius.s,
+        border_width: match (high_contrast, is_checked) {
+            (true, _) => 2.0,
+            (false, true) => 0.0,
+            (false, false) => 1.0,
+        },
+        border_color: if high_contrast {
+            base.text
+        } else {
+            accent.color
+        },
         text_color: None,
     }
 }
@@ -354,7 +502,20 @@ impl expander::StyleSheet for Theme {
 
     fn appearance(&self, style: Self::Style) -> expander::Appearance {
         match style {
-            Expander::Default => expander::Appearance::default(),
+            Expander::Default => {
+                let primary = &self.cosmic().primary;
+                expander::Appearance {
+                    background: Background::Color(primary.base.into()),
+                    border_radius: self.tokens().radius.m,
+                    border_width: if self.is_high_contrast() { 1.0 } else { 0.0 },
+                    border_color: primary.divider.into(),
+                    head_background: Background::Color(Color::TRANSPARENT),
+                    head_text_color: primary.on.into(),
+                    body_background: Background::Color(Color::TRANSPARENT),
+                    body_text_color: primary.on.into(),
+                    toggle_color: primary.on.into(),
+                }
+            }
             Expander::Custom(f) => f(self),
         }
     }
@@ -377,8 +538,18 @@ impl Default for Container {
 }
 
 impl From<fn(&Theme) -> container::Appearance> for Container {
-    fn from(_: fn(&Theme) -> container::Appearance) -> Self {
-        Self::default()
+    fn from(f: fn(&Theme) -> container::Appearance) -> Self {
+        Self::Custom(f)
+    }
+}
+
+impl Hash for Container {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        if let Container::Custom(f) = self {
+            hash_fn_ptr(*f as usize, state);
+        }
     }
 }
 
@@ -394,7 +565,7 @@ impl container::StyleSheet for Theme {
                 container::Appearance {
                     text_color: None,
                     background: palette.background.weak.color.into(),
-                    border_radius: 2.0,
+                    border_radius: self.tokens().radius.xs,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 }
@@ -433,23 +604,24 @@ impl slider::StyleSheet for Theme {
         let mut style = self.active(style);
         style.handle.shape = slider::HandleShape::Circle { radius: 16.0 };
         style.handle.border_width = 6.0;
-        style.handle.border_color = match self {
-            Theme::Dark => Color::from_rgba8(0xFF, 0xFF, 0xFF, 0.1),
-            Theme::Light => Color::from_rgba8(0, 0, 0, 0.1),
-        };
+        style.handle.border_color = with_alpha(self.cosmic().on_bg_color(), 0.1);
         style
     }
 
     fn dragging(&self, style: &Self::Style) -> slider::Appearance {
         let mut style = self.hovered(style);
-        style.handle.border_color = match self {
-            Theme::Dark => Color::from_rgba8(0xFF, 0xFF, 0xFF, 0.2),
-            Theme::Light => Color::from_rgba8(0, 0, 0, 0.2),
-        };
+        style.handle.border_color = with_alpha(self.cosmic().on_bg_color(), 0.2);
         style
     }
 }
 
+/// Applies an alpha channel to a COSMIC color.
+fn with_alpha(color: CosmicColor, alpha: f32) -> Color {
+    let mut color: Color = color.into();
+    color.a = alpha;
+    color
+}
+
 /*
  * TODO: Menu
  */
@@ -463,7 +635,7 @@ impl menu::StyleSheet for Theme {
             text_color: cosmic.primary.component.on.into(),
             background: Background::Color(cosmic.background.base.into()),
             border_width: 0.0,
-            border_radius: 16.0,
+            border_radius: self.tokens().radius.l,
             border_color: Color::TRANSPARENT,
             selected_text_color: cosmic.primary.component.on.into(),
             selected_background: Background::Color(cosmic.primary.component.hover.into()),
@@ -484,7 +656,7 @@ impl pick_list::StyleSheet for Theme {
             text_color: cosmic.on.into(),
             background: Color::TRANSPARENT.into(),
             placeholder_color: cosmic.on.into(),
-            border_radius: 24.0,
+            border_radius: self.tokens().radius.round,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             icon_size: 0.7,
@@ -538,47 +710,40 @@ impl toggler::StyleSheet for Theme {
     type Style = ();
 
     fn active(&self, _style: &Self::Style, is_active: bool) -> toggler::Appearance {
-        let palette = self.palette();
+        let cosmic = self.cosmic();
+        let palette = self.extended_palette();
 
         toggler::Appearance {
             background: if is_active {
-                palette.primary
+                cosmic.accent.base.into()
             } else {
-                //TODO: Grab neutral from palette
-                match self {
-                    Theme::Dark => Color::from_rgb8(0x78, 0x78, 0x78),
-                    Theme::Light => Color::from_rgb8(0x93, 0x93, 0x93),
-                }
-            },
-            background_border: None,
-            //TODO: Grab neutral from palette
-            foreground: match self {
-                Theme::Dark => Color::from_rgb8(0x27, 0x27, 0x27),
-                Theme::Light => Color::from_rgb8(0xe4, 0xe4, 0xe4),
+                palette.background.strong.color
             },
-            foreground_border: None,
+            background_border: self
+                .is_high_contrast()
+                .then_some(palette.background.base.text),
+            foreground: cosmic.bg_color().into(),
+            foreground_border: self
+                .is_high_contrast()
+                .then_some(palette.background.base.text),
         }
     }
 
     fn hovered(&self, style: &Self::Style, is_active: bool) -> toggler::Appearance {
-        //TODO: grab colors from palette
-        match self {
-            Theme::Dark => toggler::Appearance {
-                background: if is_active {
-                    Color::from_rgb8(0x9f, 0xed, 0xed)
-                } else {
-                    Color::from_rgb8(0xb6, 0xb6, 0xb6)
-                },
-                ..self.active(style, is_active)
-            },
-            Theme::Light => toggler::Appearance {
-                background: if is_active {
-                    Color::from_rgb8(0x00, 0x42, 0x62)
-                } else {
-                    Color::from_rgb8(0x54, 0x54, 0x54)
-                },
-                ..self.active(style, is_active)
+        let cosmic = self.cosmic();
+        let palette = self.extended_palette();
+
+        toggler::Appearance {
+            background: if is_active {
+                cosmic.accent.hover.into()
+            } else {
+                self::palette::mix(
+                    palette.background.strong.color,
+                    palette.background.base.text,
+                    0.25,
+                )
             },
+            ..self.active(style, is_active)
         }
     }
 }
@@ -682,40 +847,100 @@ impl rule::StyleSheet for Theme {
 /*
  * TODO: Scrollable
  */
+#[derive(Clone, Copy, Default)]
+pub enum Scrollable {
+    /// A scrollbar drawn within a visible track.
+    #[default]
+    Gutter,
+    /// A thin scrollbar without a track, whose scroller is only shown while the pointer is
+    /// over the content or the scroller is dragged, except in high contrast themes.
+    Overlay,
+    /// Or implement any custom theme of your liking.
+    Custom(fn(&Theme) -> scrollable::Scrollbar),
+}
+
 impl scrollable::StyleSheet for Theme {
-    type Style = ();
+    type Style = Scrollable;
 
-    fn active(&self, _style: &Self::Style) -> scrollable::Scrollbar {
+    fn active(&self, style: &Self::Style) -> scrollable::Scrollbar {
         let palette = self.extended_palette();
+        let border_width = if self.is_high_contrast() { 1.0 } else { 0.0 };
 
-        scrollable::Scrollbar {
-            background: palette.background.weak.color.into(),
-            border_radius: 4.0,
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-            scroller: scrollable::Scroller {
-                color: palette.background.strong.color,
-                border_radius: 4.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
+        match style {
+            Scrollable::Gutter => scrollable::Scrollbar {
+                background: palette.background.weak.color.into(),
+                border_radius: self.tokens().radius.s,
+                border_width,
+                border_color: palette.background.base.text,
+                scroller: scrollable::Scroller {
+                    color: palette.background.strong.color,
+                    border_radius: self.tokens().radius.s,
+                    border_width,
+                    border_color: palette.background.base.text,
+                },
             },
+            // The scrollable only draws the scroller of a scrollbar without a track while the
+            // pointer is over its content, or the scroller is dragged. High contrast themes
+            // outline the track so that the scroller is always shown.
+            Scrollable::Overlay => scrollable::Scrollbar {
+                background: None,
+                border_radius: 2.0,
+                border_width,
+                border_color: palette.background.base.text,
+                scroller: scrollable::Scroller {
+                    color: if self.is_high_contrast() {
+                        palette.background.strong.color
+                    } else {
+                        Color {
+                            a: 0.5,
+                            ..palette.background.strong.color
+                        }
+                    },
+                    border_radius: 2.0,
+                    border_width,
+                    border_color: palette.background.base.text,
+                },
+            },
+            Scrollable::Custom(f) => f(self),
         }
     }
 
-    fn hovered(&self, _style: &Self::Style) -> scrollable::Scrollbar {
+    fn hovered(&self, style: &Self::Style) -> scrollable::Scrollbar {
         let palette = self.extended_palette();
+        let active = self.active(style);
 
-        scrollable::Scrollbar {
-            background: palette.background.weak.color.into(),
-            border_radius: 4.0,
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-            scroller: scrollable::Scroller {
-                color: palette.primary.strong.color,
-                border_radius: 4.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
+        match style {
+            Scrollable::Gutter => scrollable::Scrollbar {
+                scroller: scrollable::Scroller {
+                    color: palette.primary.strong.color,
+                    ..active.scroller
+                },
+                ..active
+            },
+            Scrollable::Overlay => scrollable::Scrollbar {
+                scroller: scrollable::Scroller {
+                    color: palette.background.strong.color,
+                    ..active.scroller
+                },
+                ..active
+            },
+            Scrollable::Custom(_) => active,
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> scrollable::Scrollbar {
+        let palette = self.extended_palette();
+        let hovered = self.hovered(style);
+
+        match style {
+            Scrollable::Gutter | Scrollable::Overlay => scrollable::Scrollbar {
+                scroller: scrollable::Scroller {
+                    color: palette.primary.strong.color,
+                    ..hovered.scroller
+                },
+                ..hovered
             },
+            Scrollable::Custom(_) => hovered,
         }
     }
 }
@@ -749,6 +974,10 @@ impl Hash for Svg {
         };
 
         id.hash(state);
+
+        if let Svg::Custom(appearance) = self {
+            hash_fn_ptr(*appearance as usize, state);
+        }
     }
 }
 
@@ -794,6 +1023,22 @@ impl From<Color> for Text {
     }
 }
 
+impl Hash for Text {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Text::Color(color) => {
+                for component in [color.r, color.g, color.b, color.a] {
+                    component.to_bits().hash(state);
+                }
+            }
+            Text::Custom(f) => hash_fn_ptr(*f as usize, state),
+            Text::Accent | Text::Default => (),
+        }
+    }
+}
+
 impl text::StyleSheet for Theme {
     type Style = Text;
 
@@ -812,39 +1057,104 @@ impl text::StyleSheet for Theme {
 /*
  * TODO: Text Input
  */
+#[derive(Clone, Copy, Default)]
+pub enum TextInput {
+    /// A bordered input field.
+    #[default]
+    Default,
+    /// A rounded, filled field for searching. Pad its start to make room for an icon.
+    Search,
+    /// A borderless field for editing text in place.
+    Inline,
+    /// A field whose value failed validation.
+    Error,
+    /// A field whose value passed validation.
+    Success,
+    /// Or implement any custom theme of your liking.
+    Custom(fn(&Theme) -> text_input::Appearance),
+}
+
+impl From<fn(&Theme) -> text_input::Appearance> for TextInput {
+    fn from(f: fn(&Theme) -> text_input::Appearance) -> Self {
+        Self::Custom(f)
+    }
+}
+
 impl text_input::StyleSheet for Theme {
-    type Style = ();
+    type Style = TextInput;
 
-    fn active(&self, _style: &Self::Style) -> text_input::Appearance {
+    fn active(&self, style: &Self::Style) -> text_input::Appearance {
         let palette = self.extended_palette();
+        let border_width = if self.is_high_contrast() { 2.0 } else { 1.0 };
 
-        text_input::Appearance {
+        let bordered = |border_color| text_input::Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 2.0,
-            border_width: 1.0,
-            border_color: palette.background.strong.color,
+            border_radius: self.tokens().radius.xs,
+            border_width,
+            border_color,
+        };
+
+        match style {
+            TextInput::Default => bordered(if self.is_high_contrast() {
+                palette.background.base.text
+            } else {
+                palette.background.strong.color
+            }),
+            TextInput::Search => text_input::Appearance {
+                background: palette.background.weak.color.into(),
+                border_radius: self.tokens().radius.round,
+                border_width: if self.is_high_contrast() {
+                    border_width
+                } else {
+                    0.0
+                },
+                border_color: palette.background.base.text,
+            },
+            TextInput::Inline => text_input::Appearance {
+                background: Color::TRANSPARENT.into(),
+                border_radius: 2.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            TextInput::Error => bordered(palette.danger.base.color),
+            TextInput::Success => bordered(palette.success.base.color),
+            TextInput::Custom(f) => f(self),
         }
     }
 
-    fn hovered(&self, _style: &Self::Style) -> text_input::Appearance {
+    fn hovered(&self, style: &Self::Style) -> text_input::Appearance {
         let palette = self.extended_palette();
+        let active = self.active(style);
 
-        text_input::Appearance {
-            background: palette.background.base.color.into(),
-            border_radius: 2.0,
-            border_width: 1.0,
-            border_color: palette.background.base.text,
+        match style {
+            TextInput::Default => text_input::Appearance {
+                border_color: palette.background.base.text,
+                ..active
+            },
+            TextInput::Search | TextInput::Inline => text_input::Appearance {
+                background: palette.background.strong.color.into(),
+                ..active
+            },
+            TextInput::Error | TextInput::Success | TextInput::Custom(_) => active,
         }
     }
 
-    fn focused(&self, _style: &Self::Style) -> text_input::Appearance {
+    fn focused(&self, style: &Self::Style) -> text_input::Appearance {
         let palette = self.extended_palette();
+        let active = self.active(style);
+        let border_width = if self.is_high_contrast() { 3.0 } else { 1.0 };
 
-        text_input::Appearance {
-            background: palette.background.base.color.into(),
-            border_radius: 2.0,
-            border_width: 1.0,
-            border_color: palette.primary.strong.color,
+        match style {
+            TextInput::Default | TextInput::Search | TextInput::Inline => text_input::Appearance {
+                border_width,
+                border_color: palette.primary.strong.color,
+                ..active
+            },
+            TextInput::Error | TextInput::Success => text_input::Appearance {
+                border_width: if self.is_high_contrast() { 3.0 } else { 2.0 },
+                ..active
+            },
+            TextInput::Custom(_) => active,
         }
     }
 
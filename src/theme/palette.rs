@@ -1,7 +1,7 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-//TODO: GET CORRECT PALETTE FROM COSMIC-THEME
+use super::{CosmicTheme, COSMIC_DARK, COSMIC_HC_DARK, COSMIC_HC_LIGHT, COSMIC_LIGHT};
 use iced_core::Color;
 
 use lazy_static::lazy_static;
@@ -17,6 +17,8 @@ pub struct Palette {
 }
 
 impl Palette {
+    /// The fixed light palette, which predates deriving palettes from COSMIC themes.
+    #[deprecated(note = "use `Palette::from_cosmic` with a COSMIC theme instead")]
     #[allow(clippy::cast_precision_loss)]
     pub const LIGHT: Self = Self {
         background: Color::from_rgb(
@@ -46,6 +48,8 @@ impl Palette {
         ),
     };
 
+    /// The fixed dark palette, which predates deriving palettes from COSMIC themes.
+    #[deprecated(note = "use `Palette::from_cosmic` with a COSMIC theme instead")]
     #[allow(clippy::cast_precision_loss)]
     pub const DARK: Self = Self {
         background: Color::from_rgb(
@@ -74,8 +78,21 @@ impl Palette {
             0xb5 as f32 / 255.0,
         ),
     };
+
+    /// Derives a palette from the colors of a COSMIC theme.
+    #[must_use]
+    pub fn from_cosmic(theme: &CosmicTheme) -> Self {
+        Self {
+            background: theme.bg_color().into(),
+            text: theme.on_bg_color().into(),
+            primary: theme.accent.base.into(),
+            success: theme.success.base.into(),
+            danger: theme.destructive.base.into(),
+        }
+    }
 }
 
+#[derive(Debug)]
 pub struct Extended {
     pub background: Background,
     pub primary: Primary,
@@ -85,8 +102,10 @@ pub struct Extended {
 }
 
 lazy_static! {
-    pub static ref EXTENDED_LIGHT: Extended = Extended::generate(Palette::LIGHT);
-    pub static ref EXTENDED_DARK: Extended = Extended::generate(Palette::DARK);
+    pub static ref EXTENDED_LIGHT: Extended = Extended::from_cosmic(&COSMIC_LIGHT);
+    pub static ref EXTENDED_DARK: Extended = Extended::from_cosmic(&COSMIC_DARK);
+    pub static ref EXTENDED_HC_LIGHT: Extended = Extended::from_cosmic(&COSMIC_HC_LIGHT);
+    pub static ref EXTENDED_HC_DARK: Extended = Extended::from_cosmic(&COSMIC_HC_DARK);
 }
 
 impl Extended {
@@ -100,6 +119,33 @@ impl Extended {
             danger: Danger::generate(palette.danger, palette.background, palette.text),
         }
     }
+
+    /// Generates the extended palette from the components of a COSMIC theme.
+    #[must_use]
+    pub fn from_cosmic(theme: &CosmicTheme) -> Self {
+        let background = theme.bg_color().into();
+        let text = theme.on_bg_color().into();
+
+        Self {
+            background: Background::new(background, text),
+            primary: Primary::generate(
+                theme.accent.base.into(),
+                background,
+                theme.accent.on.into(),
+            ),
+            secondary: Secondary::generate(background, text),
+            success: Success::generate(
+                theme.success.base.into(),
+                background,
+                theme.success.on.into(),
+            ),
+            danger: Danger::generate(
+                theme.destructive.base.into(),
+                background,
+                theme.destructive.on.into(),
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -118,6 +164,7 @@ impl Pair {
     }
 }
 
+#[derive(Debug)]
 pub struct Background {
     pub base: Pair,
     pub weak: Pair,
@@ -138,6 +185,7 @@ impl Background {
     }
 }
 
+#[derive(Debug)]
 pub struct Primary {
     pub base: Pair,
     pub weak: Pair,
@@ -158,6 +206,7 @@ impl Primary {
     }
 }
 
+#[derive(Debug)]
 pub struct Secondary {
     pub base: Pair,
     pub weak: Pair,
@@ -179,6 +228,7 @@ impl Secondary {
     }
 }
 
+#[derive(Debug)]
 pub struct Success {
     pub base: Pair,
     pub weak: Pair,
@@ -199,6 +249,7 @@ impl Success {
     }
 }
 
+#[derive(Debug)]
 pub struct Danger {
     pub base: Pair,
     pub weak: Pair,
@@ -251,7 +302,7 @@ fn deviate(color: Color, amount: f32) -> Color {
     }
 }
 
-fn mix(a: Color, b: Color, factor: f32) -> Color {
+pub(crate) fn mix(a: Color, b: Color, factor: f32) -> Color {
     let a_lin = Srgb::from(a).into_linear();
     let b_lin = Srgb::from(b).into_linear();
 
@@ -269,7 +320,18 @@ fn readable(background: Color, text: Color) -> Color {
     }
 }
 
-fn is_dark(color: Color) -> bool {
+/// Composites a translucent color over an opaque background.
+pub(crate) fn composite(color: Color, background: Color) -> Color {
+    let alpha = color.a;
+
+    Color::from_rgb(
+        color.r * alpha + background.r * (1.0 - alpha),
+        color.g * alpha + background.g * (1.0 - alpha),
+        color.b * alpha + background.b * (1.0 - alpha),
+    )
+}
+
+pub(crate) fn is_dark(color: Color) -> bool {
     to_hsl(color).lightness < 0.6
 }
 
@@ -280,10 +342,10 @@ fn is_readable(a: Color, b: Color) -> bool {
     a_srgb.has_enhanced_contrast_text(&b_srgb)
 }
 
-fn to_hsl(color: Color) -> Hsl {
+pub(crate) fn to_hsl(color: Color) -> Hsl {
     Hsl::from_color(Srgb::from(color))
 }
 
-fn from_hsl(hsl: Hsl) -> Color {
+pub(crate) fn from_hsl(hsl: Hsl) -> Color {
     Srgb::from_color(hsl).into()
 }
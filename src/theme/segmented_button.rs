@@ -3,7 +3,7 @@
 
 use crate::theme::Theme;
 use crate::widget::segmented_button;
-use iced_core::{Background, BorderRadius};
+use iced_core::{Background, BorderRadius, Color};
 
 #[derive(Clone, Copy, Default)]
 pub enum SegmentedButton {
@@ -20,25 +20,29 @@ impl segmented_button::StyleSheet for Theme {
     type Style = SegmentedButton;
 
     fn horizontal(&self, style: &Self::Style) -> segmented_button::Appearance {
-        match style {
+        let appearance = match style {
             SegmentedButton::ViewSwitcher => {
                 let cosmic = self.cosmic();
+                let radius = self.tokens().radius;
                 segmented_button::Appearance {
                     border_radius: BorderRadius::from(0.0),
+                    badge_background: Some(Background::Color(cosmic.accent.base.into())),
+                    badge_text_color: cosmic.accent.on.into(),
+                    focus_outline: (2.0, cosmic.accent.focus.into()),
                     active: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.primary.component.base.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([8.0, 8.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([radius.m, radius.m, 0.0, 0.0]),
                             border_bottom: Some((4.0, cosmic.accent.base.into())),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([8.0, 8.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([radius.m, radius.m, 0.0, 0.0]),
                             border_bottom: Some((4.0, cosmic.accent.base.into())),
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([8.0, 8.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([radius.m, radius.m, 0.0, 0.0]),
                             border_bottom: Some((4.0, cosmic.accent.base.into())),
                             ..Default::default()
                         },
@@ -63,20 +67,39 @@ impl segmented_button::StyleSheet for Theme {
                         },
                         text_color: cosmic.primary.on.into(),
                     },
+                    disabled: segmented_button::ButtonStatusAppearance {
+                        background: None,
+                        first: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(0.0),
+                            border_bottom: Some((1.0, cosmic.accent.base.into())),
+                            ..Default::default()
+                        },
+                        middle: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(0.0),
+                            border_bottom: Some((1.0, cosmic.accent.base.into())),
+                            ..Default::default()
+                        },
+                        last: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(0.0),
+                            border_bottom: Some((1.0, cosmic.accent.base.into())),
+                            ..Default::default()
+                        },
+                        text_color: cosmic.primary.component.on_disabled.into(),
+                    },
                     hover: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.primary.component.hover.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([8.0, 8.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([radius.m, radius.m, 0.0, 0.0]),
                             border_bottom: Some((1.0, cosmic.accent.base.into())),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([8.0, 8.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([radius.m, radius.m, 0.0, 0.0]),
                             border_bottom: Some((1.0, cosmic.accent.base.into())),
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([8.0, 8.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([radius.m, radius.m, 0.0, 0.0]),
                             border_bottom: Some((1.0, cosmic.accent.base.into())),
                             ..Default::default()
                         },
@@ -87,14 +110,23 @@ impl segmented_button::StyleSheet for Theme {
             }
             SegmentedButton::Selection => {
                 let cosmic = self.cosmic();
+                let radius = self.tokens().radius;
                 segmented_button::Appearance {
                     border_radius: BorderRadius::from(0.0),
+                    badge_background: Some(Background::Color(cosmic.accent.base.into())),
+                    badge_text_color: cosmic.accent.on.into(),
+                    focus_outline: (2.0, cosmic.accent.focus.into()),
                     active: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(
                             cosmic.secondary.component.divider.into(),
                         )),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([24.0, 0.0, 0.0, 24.0]),
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                0.0,
+                                0.0,
+                                radius.round,
+                            ]),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
@@ -102,7 +134,12 @@ impl segmented_button::StyleSheet for Theme {
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([0.0, 24.0, 24.0, 0.0]),
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                radius.round,
+                                radius.round,
+                                0.0,
+                            ]),
                             ..Default::default()
                         },
                         text_color: cosmic.accent.base.into(),
@@ -110,7 +147,12 @@ impl segmented_button::StyleSheet for Theme {
                     inactive: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.secondary.component.base.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([24.0, 0.0, 0.0, 24.0]),
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                0.0,
+                                0.0,
+                                radius.round,
+                            ]),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
@@ -118,15 +160,53 @@ impl segmented_button::StyleSheet for Theme {
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([0.0, 24.0, 24.0, 0.0]),
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                radius.round,
+                                radius.round,
+                                0.0,
+                            ]),
                             ..Default::default()
                         },
                         text_color: cosmic.primary.on.into(),
                     },
+                    disabled: segmented_button::ButtonStatusAppearance {
+                        background: Some(Background::Color(
+                            cosmic.secondary.component.disabled.into(),
+                        )),
+                        first: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                0.0,
+                                0.0,
+                                radius.round,
+                            ]),
+                            ..Default::default()
+                        },
+                        middle: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(0.0),
+                            ..Default::default()
+                        },
+                        last: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                radius.round,
+                                radius.round,
+                                0.0,
+                            ]),
+                            ..Default::default()
+                        },
+                        text_color: cosmic.secondary.component.on_disabled.into(),
+                    },
                     hover: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.primary.component.hover.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([24.0, 0.0, 0.0, 24.0]),
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                0.0,
+                                0.0,
+                                radius.round,
+                            ]),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
@@ -134,7 +214,12 @@ impl segmented_button::StyleSheet for Theme {
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([0.0, 24.0, 24.0, 0.0]),
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                radius.round,
+                                radius.round,
+                                0.0,
+                            ]),
                             ..Default::default()
                         },
                         text_color: cosmic.accent.base.into(),
@@ -142,28 +227,38 @@ impl segmented_button::StyleSheet for Theme {
                     ..Default::default()
                 }
             }
-            SegmentedButton::Custom(func) => func(self),
+            SegmentedButton::Custom(func) => return func(self),
+        };
+
+        if self.is_high_contrast() {
+            high_contrast(self, appearance)
+        } else {
+            appearance
         }
     }
 
     fn vertical(&self, style: &Self::Style) -> segmented_button::Appearance {
-        match style {
+        let appearance = match style {
             SegmentedButton::ViewSwitcher => {
                 let cosmic = self.cosmic();
+                let radius = self.tokens().radius;
                 segmented_button::Appearance {
                     border_radius: BorderRadius::from(0.0),
+                    badge_background: Some(Background::Color(cosmic.accent.base.into())),
+                    badge_text_color: cosmic.accent.on.into(),
+                    focus_outline: (2.0, cosmic.accent.focus.into()),
                     active: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.primary.component.base.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         text_color: cosmic.accent.base.into(),
@@ -171,31 +266,47 @@ impl segmented_button::StyleSheet for Theme {
                     inactive: segmented_button::ButtonStatusAppearance {
                         background: None,
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         text_color: cosmic.primary.on.into(),
                     },
+                    disabled: segmented_button::ButtonStatusAppearance {
+                        background: None,
+                        first: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(radius.round),
+                            ..Default::default()
+                        },
+                        middle: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(radius.round),
+                            ..Default::default()
+                        },
+                        last: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(radius.round),
+                            ..Default::default()
+                        },
+                        text_color: cosmic.primary.component.on_disabled.into(),
+                    },
                     hover: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.primary.component.hover.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from(24.0),
+                            border_radius: BorderRadius::from(radius.round),
                             ..Default::default()
                         },
                         text_color: cosmic.accent.base.into(),
@@ -205,14 +316,23 @@ impl segmented_button::StyleSheet for Theme {
             }
             SegmentedButton::Selection => {
                 let cosmic = self.cosmic();
+                let radius = self.tokens().radius;
                 segmented_button::Appearance {
                     border_radius: BorderRadius::from(0.0),
+                    badge_background: Some(Background::Color(cosmic.accent.base.into())),
+                    badge_text_color: cosmic.accent.on.into(),
+                    focus_outline: (2.0, cosmic.accent.focus.into()),
                     active: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(
                             cosmic.secondary.component.divider.into(),
                         )),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([24.0, 24.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                radius.round,
+                                0.0,
+                                0.0,
+                            ]),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
@@ -220,7 +340,12 @@ impl segmented_button::StyleSheet for Theme {
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([0.0, 0.0, 24.0, 24.0]),
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                0.0,
+                                radius.round,
+                                radius.round,
+                            ]),
                             ..Default::default()
                         },
                         text_color: cosmic.accent.base.into(),
@@ -228,7 +353,12 @@ impl segmented_button::StyleSheet for Theme {
                     inactive: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.secondary.component.base.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([24.0, 24.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                radius.round,
+                                0.0,
+                                0.0,
+                            ]),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
@@ -236,15 +366,53 @@ impl segmented_button::StyleSheet for Theme {
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([0.0, 0.0, 24.0, 24.0]),
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                0.0,
+                                radius.round,
+                                radius.round,
+                            ]),
                             ..Default::default()
                         },
                         text_color: cosmic.primary.on.into(),
                     },
+                    disabled: segmented_button::ButtonStatusAppearance {
+                        background: Some(Background::Color(
+                            cosmic.secondary.component.disabled.into(),
+                        )),
+                        first: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                radius.round,
+                                0.0,
+                                0.0,
+                            ]),
+                            ..Default::default()
+                        },
+                        middle: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from(0.0),
+                            ..Default::default()
+                        },
+                        last: segmented_button::ButtonAppearance {
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                0.0,
+                                radius.round,
+                                radius.round,
+                            ]),
+                            ..Default::default()
+                        },
+                        text_color: cosmic.secondary.component.on_disabled.into(),
+                    },
                     hover: segmented_button::ButtonStatusAppearance {
                         background: Some(Background::Color(cosmic.primary.component.hover.into())),
                         first: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([24.0, 24.0, 0.0, 0.0]),
+                            border_radius: BorderRadius::from([
+                                radius.round,
+                                radius.round,
+                                0.0,
+                                0.0,
+                            ]),
                             ..Default::default()
                         },
                         middle: segmented_button::ButtonAppearance {
@@ -252,7 +420,12 @@ impl segmented_button::StyleSheet for Theme {
                             ..Default::default()
                         },
                         last: segmented_button::ButtonAppearance {
-                            border_radius: BorderRadius::from([0.0, 0.0, 24.0, 24.0]),
+                            border_radius: BorderRadius::from([
+                                0.0,
+                                0.0,
+                                radius.round,
+                                radius.round,
+                            ]),
                             ..Default::default()
                         },
                         text_color: cosmic.accent.base.into(),
@@ -260,7 +433,45 @@ impl segmented_button::StyleSheet for Theme {
                     ..Default::default()
                 }
             }
-            SegmentedButton::Custom(func) => func(self),
+            SegmentedButton::Custom(func) => return func(self),
+        };
+
+        if self.is_high_contrast() {
+            high_contrast(self, appearance)
+        } else {
+            appearance
         }
     }
 }
+
+/// Outlines each button, and marks the active button with a thicker accent border.
+fn high_contrast(
+    theme: &Theme,
+    mut appearance: segmented_button::Appearance,
+) -> segmented_button::Appearance {
+    let text = theme.extended_palette().background.base.text;
+    let accent: Color = theme.cosmic().accent.base.into();
+    let disabled = appearance.disabled.text_color;
+
+    for (status, width, color) in [
+        (&mut appearance.active, 2.0, accent),
+        (&mut appearance.inactive, 1.0, text),
+        (&mut appearance.hover, 1.0, text),
+        (&mut appearance.disabled, 1.0, disabled),
+    ] {
+        let outline = Some((width, color));
+
+        for button in [&mut status.first, &mut status.middle, &mut status.last] {
+            button.border_top = outline;
+            button.border_start = outline;
+            button.border_end = outline;
+            button.border_bottom = button
+                .border_bottom
+                .filter(|(bottom, _)| *bottom > width)
+                .or(outline);
+        }
+    }
+
+    appearance.focus_outline.0 = 3.0;
+    appearance
+}
@@ -0,0 +1,121 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+use super::Theme;
+use iced::{
+    futures::{
+        channel::mpsc::{unbounded, UnboundedReceiver},
+        StreamExt,
+    },
+    subscription,
+};
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, Debouncer,
+};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Time to wait for writes to a theme file to settle before reloading it.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Watches a theme file, emitting a new [`Theme`] whenever it changes on disk.
+///
+/// The theme is emitted once when the subscription starts. Changes to the file are
+/// debounced so that partially-written files are not loaded, and the previously
+/// emitted theme remains in use if the file fails to parse. Files are read on the
+/// watcher's thread, so that the executor is never blocked by the file system.
+pub fn theme_subscription<I: 'static + Hash + Copy + Send + Sync + Debug>(
+    id: I,
+    path: impl Into<PathBuf>,
+) -> iced::Subscription<(I, Theme)> {
+    let path = path.into();
+
+    // The path is part of the identity, so that watching another file restarts the watcher.
+    subscription::unfold((id, path.clone()), State::Ready(path), move |state| {
+        start_watching(id, state)
+    })
+}
+
+pub enum State {
+    Ready(PathBuf),
+    Watching(
+        Debouncer<RecommendedWatcher>,
+        UnboundedReceiver<Option<Theme>>,
+    ),
+    Finished,
+}
+
+async fn start_watching<I: Copy + Debug>(id: I, state: State) -> (Option<(I, Theme)>, State) {
+    match state {
+        State::Ready(path) => {
+            let (tx, rx) = unbounded();
+
+            let file_name = path.file_name().map(ToOwned::to_owned);
+            let watched_path = path.clone();
+            let watcher_tx = tx.clone();
+            let debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |result: DebounceEventResult| {
+                if let Ok(events) = result {
+                    // Atomic writes replace the file, so the parent directory is watched instead.
+                    if events
+                        .iter()
+                        .any(|event| event.path.file_name() == file_name.as_deref())
+                    {
+                        let _ = watcher_tx.unbounded_send(load(&watched_path));
+                    }
+                }
+            });
+
+            let mut debouncer = match debouncer {
+                Ok(debouncer) => debouncer,
+                Err(why) => {
+                    eprintln!("failed to watch theme {:?}: {}", id, why);
+                    return (None, State::Finished);
+                }
+            };
+
+            let directory = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or_else(|| Path::new("."));
+
+            if let Err(why) = debouncer
+                .watcher()
+                .watch(directory, RecursiveMode::NonRecursive)
+            {
+                eprintln!("failed to watch {}: {}", directory.display(), why);
+                return (None, State::Finished);
+            }
+
+            // The initial theme is loaded off the executor, like every reload.
+            std::thread::spawn(move || {
+                let _ = tx.unbounded_send(load(&path));
+            });
+
+            (None, State::Watching(debouncer, rx))
+        }
+        State::Watching(debouncer, mut rx) => match rx.next().await {
+            Some(theme) => (
+                theme.map(|theme| (id, theme)),
+                State::Watching(debouncer, rx),
+            ),
+            None => (None, State::Finished),
+        },
+        State::Finished => iced::futures::future::pending().await,
+    }
+}
+
+fn load(path: &Path) -> Option<Theme> {
+    match Theme::from_file(path) {
+        Ok(theme) => Some(theme),
+        Err(why) => {
+            eprintln!("{}, keeping the previous theme", why);
+            None
+        }
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Design tokens for the spacing, radii, and sizes of widgets.
+//!
+//! Each [`Theme`](super::Theme) has a [`Density`], whose tokens styles read through
+//! [`Theme::tokens`](super::Theme::tokens). Widgets are laid out without access to the
+//! theme, so they are given the density of the theme by their `density` builder methods.
+
+/// The density of the interface, which scales the spacing and sizing of every widget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Density {
+    /// Tighter spacing and shorter controls, fitting more content on screen.
+    Compact,
+    /// The default spacing and sizing of COSMIC.
+    #[default]
+    Standard,
+    /// Looser spacing and taller controls, for touch screens.
+    Roomy,
+}
+
+impl Density {
+    /// The design tokens of this density.
+    #[must_use]
+    pub const fn tokens(self) -> Tokens {
+        match self {
+            Density::Compact => Tokens {
+                spacing: Spacing {
+                    xxs: 2,
+                    xs: 6,
+                    s: 8,
+                    m: 12,
+                    l: 18,
+                    xl: 24,
+                },
+                radius: Radius::STANDARD,
+                padding: Padding {
+                    list: [12, 4],
+                    settings_item: 14,
+                },
+                size: Size {
+                    control: 28,
+                    tab: 40,
+                    header_bar: 42,
+                    content_width: 678,
+                },
+            },
+            Density::Standard => Tokens {
+                spacing: Spacing {
+                    xxs: 4,
+                    xs: 8,
+                    s: 12,
+                    m: 16,
+                    l: 24,
+                    xl: 32,
+                },
+                radius: Radius::STANDARD,
+                padding: Padding {
+                    list: [16, 6],
+                    settings_item: 18,
+                },
+                size: Size {
+                    control: 32,
+                    tab: 48,
+                    header_bar: 50,
+                    content_width: 678,
+                },
+            },
+            Density::Roomy => Tokens {
+                spacing: Spacing {
+                    xxs: 6,
+                    xs: 10,
+                    s: 16,
+                    m: 20,
+                    l: 32,
+                    xl: 40,
+                },
+                radius: Radius::STANDARD,
+                padding: Padding {
+                    list: [20, 8],
+                    settings_item: 22,
+                },
+                size: Size {
+                    control: 40,
+                    tab: 56,
+                    header_bar: 58,
+                    content_width: 760,
+                },
+            },
+        }
+    }
+}
+
+/// Spacing, radius, padding, and size tokens shared by all widgets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tokens {
+    pub spacing: Spacing,
+    pub radius: Radius,
+    pub padding: Padding,
+    pub size: Size,
+}
+
+impl Default for Tokens {
+    fn default() -> Self {
+        Density::default().tokens()
+    }
+}
+
+/// Spacing between and around widgets, from smallest to largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spacing {
+    pub xxs: u16,
+    pub xs: u16,
+    pub s: u16,
+    pub m: u16,
+    pub l: u16,
+    pub xl: u16,
+}
+
+/// Corner radii, from smallest to fully rounded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radius {
+    pub xs: f32,
+    pub s: f32,
+    pub m: f32,
+    pub l: f32,
+    pub round: f32,
+}
+
+impl Radius {
+    const STANDARD: Self = Self {
+        xs: 2.0,
+        s: 4.0,
+        m: 8.0,
+        l: 16.0,
+        round: 24.0,
+    };
+}
+
+/// Padding inside widgets whose insets fall between the steps of [`Spacing`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Padding {
+    /// Vertical and horizontal padding around the rows of a list column.
+    pub list: [u16; 2],
+    /// Horizontal padding at either end of a settings item.
+    pub settings_item: u16,
+}
+
+/// Heights of controls, and widths of content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    /// Height of buttons, inputs, and segmented selections.
+    pub control: u16,
+    /// Height of tabs in view switchers.
+    pub tab: u16,
+    /// Height of the header bar.
+    pub header_bar: u16,
+    /// Maximum width of content in a settings page.
+    pub content_width: u32,
+}
@@ -0,0 +1,156 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Cross-fades between two themes, such as when the desktop switches to dark mode.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! // When the theme changes, start a transition from the current theme.
+//! self.transition = Some(Transition::new(&self.theme, theme, Duration::from_millis(300)));
+//!
+//! // Subscribe to ticks only while a transition is in progress.
+//! if self.transition.is_some() {
+//!     transition_subscription().map(Message::ThemeTick)
+//! }
+//!
+//! // On every tick, update the theme until the transition has finished.
+//! if let Some(transition) = &self.transition {
+//!     self.theme = transition.theme_at(now);
+//!     if transition.is_finished_at(now) {
+//!         self.transition = None;
+//!     }
+//! }
+//! ```
+
+use super::{CosmicColor, CosmicComponent, CosmicTheme, CustomTheme, Theme};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Interval between the frames of a transition.
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// An animated transition from one theme to another.
+#[derive(Debug, Clone)]
+pub struct Transition {
+    from: CosmicTheme,
+    to: Theme,
+    started: Instant,
+    duration: Duration,
+}
+
+impl Transition {
+    /// Starts a transition from the `from` theme which reaches the `to` theme after `duration`.
+    #[must_use]
+    pub fn new(from: &Theme, to: Theme, duration: Duration) -> Self {
+        Self {
+            from: from.cosmic().clone(),
+            to,
+            started: Instant::now(),
+            duration,
+        }
+    }
+
+    /// The theme that the transition ends with.
+    #[must_use]
+    pub fn target(&self) -> &Theme {
+        &self.to
+    }
+
+    /// Whether the transition has reached its target theme at `now`.
+    #[must_use]
+    pub fn is_finished_at(&self, now: Instant) -> bool {
+        now.duration_since(self.started) >= self.duration
+    }
+
+    /// The eased progress of the transition at `now`, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn progress_at(&self, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        let t = (now.duration_since(self.started).as_secs_f32() / self.duration.as_secs_f32())
+            .clamp(0.0, 1.0);
+
+        // Smoothstep, which eases in and out of the transition.
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    /// The blended theme at `now`, which is the target theme once finished.
+    #[must_use]
+    pub fn theme_at(&self, now: Instant) -> Theme {
+        if self.is_finished_at(now) {
+            return self.to.clone();
+        }
+
+        Theme::Custom(Arc::new(CustomTheme::derived(
+            interpolate(&self.from, self.to.cosmic(), self.progress_at(now)),
+            self.to.density(),
+            self.to.is_high_contrast(),
+        )))
+    }
+}
+
+/// Emits the current time at the frame rate of a transition.
+///
+/// Subscribe to this only while a [`Transition`] is in progress.
+#[cfg(feature = "tokio")]
+#[must_use]
+pub fn transition_subscription() -> iced::Subscription<Instant> {
+    iced::time::every(FRAME_INTERVAL)
+}
+
+/// Blends every color of two COSMIC themes, where a `factor` of `0.0` is `from`.
+///
+/// Properties which are not colors are taken from the nearest of the two themes.
+#[must_use]
+pub fn interpolate(from: &CosmicTheme, to: &CosmicTheme, factor: f32) -> CosmicTheme {
+    let mut theme = if factor < 0.5 {
+        from.clone()
+    } else {
+        to.clone()
+    };
+
+    for (container, from, to) in [
+        (&mut theme.background, &from.background, &to.background),
+        (&mut theme.primary, &from.primary, &to.primary),
+        (&mut theme.secondary, &from.secondary, &to.secondary),
+    ] {
+        container.base = lerp(from.base, to.base, factor);
+        container.divider = lerp(from.divider, to.divider, factor);
+        container.on = lerp(from.on, to.on, factor);
+        container.component = component(&from.component, &to.component, factor);
+    }
+
+    theme.accent = component(&from.accent, &to.accent, factor);
+    theme.success = component(&from.success, &to.success, factor);
+    theme.destructive = component(&from.destructive, &to.destructive, factor);
+
+    theme
+}
+
+fn component(from: &CosmicComponent, to: &CosmicComponent, factor: f32) -> CosmicComponent {
+    CosmicComponent {
+        base: lerp(from.base, to.base, factor),
+        hover: lerp(from.hover, to.hover, factor),
+        pressed: lerp(from.pressed, to.pressed, factor),
+        selected: lerp(from.selected, to.selected, factor),
+        selected_text: lerp(from.selected_text, to.selected_text, factor),
+        focus: lerp(from.focus, to.focus, factor),
+        divider: lerp(from.divider, to.divider, factor),
+        on: lerp(from.on, to.on, factor),
+        disabled: lerp(from.disabled, to.disabled, factor),
+        on_disabled: lerp(from.on_disabled, to.on_disabled, factor),
+    }
+}
+
+fn lerp(from: CosmicColor, to: CosmicColor, factor: f32) -> CosmicColor {
+    let channel = |a: f32, b: f32| a + (b - a) * factor;
+    CosmicColor::new(
+        channel(from.red, to.red),
+        channel(from.green, to.green),
+        channel(from.blue, to.blue),
+        channel(from.alpha, to.alpha),
+    )
+}
@@ -10,6 +10,7 @@ pub const fn button<Message>(style: theme::Button) -> Button<Message> {
     Button {
         style,
         message: None,
+        density: theme::Density::Standard,
     }
 }
 
@@ -17,6 +18,7 @@ pub const fn button<Message>(style: theme::Button) -> Button<Message> {
 pub struct Button<Message> {
     style: theme::Button,
     message: Option<Message>,
+    density: theme::Density,
 }
 
 impl<Message: 'static> Button<Message> {
@@ -27,6 +29,13 @@ impl<Message: 'static> Button<Message> {
         self
     }
 
+    /// Sets the density of the theme, which pads the button.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.density = density;
+        self
+    }
+
     /// A button with an icon.
     pub fn icon(
         self,
@@ -44,9 +53,10 @@ impl<Message: 'static> Button<Message> {
 
     /// A custom button that has the desired default spacing and padding.
     pub fn custom(self, children: Vec<Element<Message>>) -> widget::Button<Message, Renderer> {
-        let button = widget::button(widget::row(children).spacing(8))
+        let spacing = self.density.tokens().spacing;
+        let button = widget::button(widget::row(children).spacing(spacing.xs))
             .style(self.style)
-            .padding([8, 16]);
+            .padding([spacing.xs, spacing.m]);
 
         if let Some(message) = self.message {
             button.on_press(message)
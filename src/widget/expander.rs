@@ -0,0 +1,473 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A collapsible container with a clickable header.
+
+use crate::theme::{self, expander::StyleSheet};
+use crate::widget::IconHandle;
+use crate::{Element, Renderer};
+use iced::{Length, Point, Rectangle, Size};
+use iced_core::BorderRadius;
+use iced_native::event::{self, Event};
+use iced_native::widget::{tree, Operation, Tree};
+use iced_native::{layout, mouse, overlay, renderer, touch, Renderer as _};
+use iced_native::{Clipboard, Layout, Shell, Widget};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+/// Size of the chevron which indicates whether the expander is expanded.
+const CHEVRON_SIZE: u16 = 16;
+
+/// A collapsible container with a clickable header, which reveals its body when expanded.
+///
+/// The expander remembers whether it is expanded unless [`Expander::expanded`] is set.
+#[must_use]
+pub fn expander<'a, Message: 'static>(
+    title: impl Into<Cow<'a, str>>,
+    body: impl Into<Element<'a, Message>>,
+) -> Expander<'a, Message> {
+    Expander::new(title, body)
+}
+
+/// A collapsible container with a clickable header, which reveals its body when expanded.
+#[allow(missing_debug_implementations)]
+pub struct Expander<'a, Message> {
+    title: Element<'a, Message>,
+    subtitle: Option<Element<'a, Message>>,
+    body: Element<'a, Message>,
+    expanded: Option<bool>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    style: theme::Expander,
+    width: Length,
+    density: theme::Density,
+    /// Whether the body is shown.
+    ///
+    /// Layout has no access to the tree, so this starts as the controlled state, or
+    /// collapsed, and is copied from the tree when the expander is rebuilt.
+    is_expanded: Cell<bool>,
+    chevron_collapsed: IconHandle,
+    chevron_expanded: IconHandle,
+}
+
+impl<'a, Message: 'static> Expander<'a, Message> {
+    /// Creates an expander with a title, which is collapsed by default.
+    pub fn new(title: impl Into<Cow<'a, str>>, body: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            title: super::text::body(title.into()).into(),
+            subtitle: None,
+            body: body.into(),
+            expanded: None,
+            on_toggle: None,
+            style: theme::Expander::default(),
+            width: Length::Fill,
+            density: theme::Density::default(),
+            is_expanded: Cell::new(false),
+            chevron_collapsed: IconHandle::named("go-next-symbolic", CHEVRON_SIZE),
+            chevron_expanded: IconHandle::named("go-down-symbolic", CHEVRON_SIZE),
+        }
+    }
+
+    /// Sets the subtitle which is shown beneath the title.
+    #[must_use]
+    pub fn subtitle(mut self, subtitle: impl Into<Cow<'a, str>>) -> Self {
+        self.subtitle = Some(super::text::caption(subtitle.into()).into());
+        self
+    }
+
+    /// Controls whether the expander is expanded, instead of remembering it internally.
+    ///
+    /// Use with [`Expander::on_toggle`] to update the expanded state when clicked.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self.is_expanded.set(expanded);
+        self
+    }
+
+    /// Emits a message with the new expanded state when the header is clicked.
+    #[must_use]
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the style of the expander.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<theme::Expander>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of the expander.
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the density of the theme, which pads the header.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// The title, body, and subtitle, in the order of their trees and layouts.
+    fn elements(&self) -> Vec<&Element<'a, Message>> {
+        let mut elements = vec![&self.title, &self.body];
+        elements.extend(self.subtitle.as_ref());
+        elements
+    }
+}
+
+impl<'a, Message: 'static> Widget<Message, Renderer> for Expander<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            expanded: self.expanded.unwrap_or(false),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements().into_iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        if let Some(expanded) = self.expanded {
+            state.expanded = expanded;
+        }
+
+        self.is_expanded.set(state.expanded);
+        tree.diff_children(&self.elements());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let tokens = self.density.tokens();
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max = limits.max();
+
+        let padding_x = f32::from(tokens.spacing.m);
+        let padding_y = f32::from(tokens.spacing.xs);
+        let chevron = f32::from(CHEVRON_SIZE) + f32::from(tokens.spacing.s);
+
+        let text_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new((max.width - 2.0 * padding_x - chevron).max(0.0), max.height),
+        );
+
+        let mut title = self.title.as_widget().layout(renderer, &text_limits);
+        let mut subtitle = self
+            .subtitle
+            .as_ref()
+            .map(|subtitle| subtitle.as_widget().layout(renderer, &text_limits));
+
+        let text_size = Size::new(
+            title
+                .size()
+                .width
+                .max(subtitle.as_ref().map_or(0.0, |node| node.size().width)),
+            title.size().height + subtitle.as_ref().map_or(0.0, |node| node.size().height),
+        );
+
+        let header_height =
+            (text_size.height + 2.0 * padding_y).max(f32::from(tokens.size.control));
+        let text_y = (header_height - text_size.height) / 2.0;
+
+        title.move_to(Point::new(padding_x, text_y));
+        if let Some(subtitle) = subtitle.as_mut() {
+            subtitle.move_to(Point::new(padding_x, text_y + title.size().height));
+        }
+
+        let mut width = text_size.width + 2.0 * padding_x + chevron;
+        let mut height = header_height;
+
+        let body = if self.is_expanded.get() {
+            let body_limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (max.width - 2.0 * padding_x).max(0.0),
+                    (max.height - header_height - padding_y).max(0.0),
+                ),
+            );
+
+            let mut body = self.body.as_widget().layout(renderer, &body_limits);
+            body.move_to(Point::new(padding_x, header_height));
+
+            width = width.max(body.size().width + 2.0 * padding_x);
+            height += body.size().height + padding_y;
+            body
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let size = limits.resolve(Size::new(width, height));
+
+        let mut children = vec![title, body];
+        children.extend(subtitle);
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(&self, tree: &mut Tree, layout: Layout<'_>, operation: &mut dyn Operation<Message>) {
+        if self.is_expanded.get() {
+            let body = layout.children().nth(1).expect("expander body layout");
+            self.body
+                .as_widget()
+                .operate(&mut tree.children[1], body, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let body = layout.children().nth(1).expect("expander body layout");
+
+        if self.is_expanded.get() {
+            let status = self.body.as_widget_mut().on_event(
+                &mut tree.children[1],
+                event.clone(),
+                body,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) = event
+        {
+            if self.header_bounds(layout).contains(cursor_position) {
+                let expanded = !self.is_expanded.get();
+
+                if self.expanded.is_none() {
+                    tree.state.downcast_mut::<State>().expanded = expanded;
+                    self.is_expanded.set(expanded);
+                    shell.invalidate_layout();
+                }
+
+                if let Some(on_toggle) = self.on_toggle.as_ref() {
+                    shell.publish(on_toggle(expanded));
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.header_bounds(layout).contains(cursor_position) {
+            return mouse::Interaction::Pointer;
+        }
+
+        if self.is_expanded.get() {
+            let body = layout.children().nth(1).expect("expander body layout");
+            return self.body.as_widget().mouse_interaction(
+                &tree.children[1],
+                body,
+                cursor_position,
+                viewport,
+                renderer,
+            );
+        }
+
+        mouse::Interaction::Idle
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let appearance = StyleSheet::appearance(theme, self.style);
+        let bounds = layout.bounds();
+        let header = self.header_bounds(layout);
+        let radius = appearance.border_radius;
+        let expanded = self.is_expanded.get();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: BorderRadius::from(radius),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header,
+                border_radius: BorderRadius::from(if expanded {
+                    [radius, radius, 0.0, 0.0]
+                } else {
+                    [radius; 4]
+                }),
+                border_width: 0.0,
+                border_color: iced::Color::TRANSPARENT,
+            },
+            appearance.head_background,
+        );
+
+        let mut children = layout.children();
+        let title = children.next().expect("expander title layout");
+        let body = children.next().expect("expander body layout");
+        let subtitle = children.next();
+
+        // Draw the title and subtitle in the header.
+        let head_style = renderer::Style {
+            text_color: appearance.head_text_color,
+        };
+
+        self.title.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &head_style,
+            title,
+            cursor_position,
+            viewport,
+        );
+
+        if let (Some(element), Some(layout)) = (self.subtitle.as_ref(), subtitle) {
+            element.as_widget().draw(
+                &tree.children[2],
+                renderer,
+                theme,
+                &head_style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        // Draw the chevron at the end of the header.
+        let chevron = f32::from(CHEVRON_SIZE);
+        let icon = if expanded {
+            &self.chevron_expanded
+        } else {
+            &self.chevron_collapsed
+        };
+
+        icon.draw(
+            renderer,
+            Rectangle {
+                x: header.x + header.width - f32::from(theme.tokens().spacing.m) - chevron,
+                y: header.center_y() - chevron / 2.0,
+                width: chevron,
+                height: chevron,
+            },
+            appearance.toggle_color,
+        );
+
+        if expanded {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: header.y + header.height,
+                        height: bounds.height - header.height,
+                        ..bounds
+                    },
+                    border_radius: BorderRadius::from([0.0, 0.0, radius, radius]),
+                    border_width: 0.0,
+                    border_color: iced::Color::TRANSPARENT,
+                },
+                appearance.body_background,
+            );
+
+            self.body.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.body_text_color,
+                },
+                body,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        if !self.is_expanded.get() {
+            return None;
+        }
+
+        let body = layout.children().nth(1)?;
+        self.body
+            .as_widget()
+            .overlay(&mut tree.children[1], body, renderer)
+    }
+}
+
+impl<'a, Message: 'static> Expander<'a, Message> {
+    /// The bounds of the clickable header.
+    fn header_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+
+        if self.is_expanded.get() {
+            if let Some(body) = layout.children().nth(1) {
+                return Rectangle {
+                    height: body.bounds().y - bounds.y,
+                    ..bounds
+                };
+            }
+        }
+
+        bounds
+    }
+}
+
+impl<'a, Message: 'static> From<Expander<'a, Message>> for Element<'a, Message> {
+    fn from(expander: Expander<'a, Message>) -> Self {
+        Self::new(expander)
+    }
+}
+
+/// State that is maintained by each expander.
+struct State {
+    /// Whether the expander is expanded, when it is not controlled by the application.
+    expanded: bool,
+}
@@ -17,6 +17,7 @@ pub fn header_bar<'a, Message>() -> HeaderBar<'a, Message> {
         start: None,
         center: None,
         end: None,
+        density: theme::Density::default(),
     }
 }
 
@@ -37,6 +38,8 @@ pub struct HeaderBar<'a, Message> {
     center: Option<Element<'a, Message>>,
     #[setters(strip_option)]
     end: Option<Element<'a, Message>>,
+    /// The density of the theme, which sizes the header bar.
+    density: theme::Density,
 }
 
 impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
@@ -69,9 +72,10 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
             self.window_controls()
         });
 
+        let tokens = self.density.tokens();
         let mut widget = widget::row(packed)
-            .height(Length::Units(50))
-            .padding(8)
+            .height(Length::Units(tokens.size.header_bar))
+            .padding(tokens.spacing.xs)
             .apply(widget::container)
             .center_y()
             .apply(widget::mouse_listener);
@@ -88,7 +92,7 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
     }
 
     fn title_widget(&self) -> Element<'a, Message> {
-        widget::container(widget::text(self.title))
+        widget::container(super::text::heading(self.title))
             .center_x()
             .center_y()
             .width(Length::Fill)
@@ -122,7 +126,7 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
         }
 
         widget::row(widgets)
-            .spacing(8)
+            .spacing(self.density.tokens().spacing.xs)
             .apply(widget::container)
             .height(Length::Fill)
             .center_y()
@@ -6,12 +6,12 @@
 use crate::{Element, Renderer};
 use derive_setters::Setters;
 use iced::{
-    widget::{svg, Image},
-    ContentFit, Length,
+    widget::{image, svg, Image},
+    Color, ContentFit, Length, Rectangle,
 };
 use std::{
-    borrow::Cow, collections::hash_map::DefaultHasher, ffi::OsStr, hash::Hash, hash::Hasher,
-    path::Path, path::PathBuf,
+    borrow::Cow, cell::RefCell, collections::hash_map::DefaultHasher, collections::HashMap,
+    ffi::OsStr, hash::Hash, hash::Hasher, path::Path, path::PathBuf,
 };
 
 #[derive(Debug, Hash)]
@@ -19,6 +19,8 @@ pub enum IconSource<'a> {
     Path(Cow<'a, Path>),
     Name(Cow<'a, str>),
     Embedded(Image),
+    Svg(svg::Handle),
+    Image(image::Handle),
 }
 
 impl<'a> From<Cow<'a, Path>> for IconSource<'a> {
@@ -63,6 +65,18 @@ impl<'a> From<Image> for IconSource<'a> {
     }
 }
 
+impl<'a> From<svg::Handle> for IconSource<'a> {
+    fn from(value: svg::Handle) -> Self {
+        Self::Svg(value)
+    }
+}
+
+impl<'a> From<image::Handle> for IconSource<'a> {
+    fn from(value: image::Handle) -> Self {
+        Self::Image(value)
+    }
+}
+
 /// A lazily-generated icon.
 #[derive(Hash, Setters)]
 pub struct Icon<'a> {
@@ -98,7 +112,11 @@ pub fn icon<'a>(name: impl Into<IconSource<'a>>, size: u16) -> Icon<'a> {
 
 impl<'a> Icon<'a> {
     #[must_use]
-    fn into_element<Message: 'static>(self) -> Element<'a, Message> {
+    fn into_element<Message: 'static>(mut self) -> Element<'a, Message> {
+        if let IconSource::Image(handle) = &self.name {
+            self.name = IconSource::Embedded(Image::new(handle.clone()));
+        }
+
         if let IconSource::Embedded(mut image) = self.name {
             image = image
                 .width(self.width.unwrap_or(Length::Units(self.size)))
@@ -123,27 +141,11 @@ impl<'a> Icon<'a> {
             let icon: Option<&Path> = match &self.name {
                 IconSource::Path(path) => Some(path),
                 IconSource::Name(name) => {
-                    let icon = crate::settings::DEFAULT_ICON_THEME.with(|default_theme| {
-                        let default_theme: &str = &default_theme.borrow();
-                        freedesktop_icons::lookup(name)
-                            .with_size(self.size)
-                            .with_theme(self.theme.as_deref().unwrap_or(default_theme))
-                            .with_cache()
-                            .find()
-                    });
-
-                    name_path_buffer = if icon.is_none() {
-                        freedesktop_icons::lookup(name)
-                            .with_size(self.size)
-                            .with_cache()
-                            .find()
-                    } else {
-                        icon
-                    };
-
+                    name_path_buffer = lookup(name, self.size, self.theme.as_deref());
                     name_path_buffer.as_deref()
                 }
-                IconSource::Embedded(_) => unimplemented!(),
+                IconSource::Svg(_) => None,
+                IconSource::Embedded(_) | IconSource::Image(_) => unimplemented!(),
             };
 
             let is_svg = self.force_svg
@@ -152,7 +154,9 @@ impl<'a> Icon<'a> {
                     .map_or(true, |path| path.extension() == Some(OsStr::new("svg")));
 
             if is_svg {
-                let handle = if let Some(path) = icon {
+                let handle = if let IconSource::Svg(handle) = &self.name {
+                    handle.clone()
+                } else if let Some(path) = icon {
                     svg::Handle::from_path(path)
                 } else {
                     eprintln!("icon '{:?}' size {} not found", &self.name, self.size);
@@ -184,6 +188,126 @@ impl<'a> Icon<'a> {
     }
 }
 
+/// Finds the path of a named icon in the `theme`, or the default icon theme.
+///
+/// Icons missing from the theme are searched for in the fallback `hicolor` theme.
+pub(crate) fn lookup(name: &str, size: u16, theme: Option<&str>) -> Option<PathBuf> {
+    let icon = crate::settings::DEFAULT_ICON_THEME.with(|default_theme| {
+        let default_theme: &str = &default_theme.borrow();
+        freedesktop_icons::lookup(name)
+            .with_size(size)
+            .with_theme(theme.unwrap_or(default_theme))
+            .with_cache()
+            .find()
+    });
+
+    icon.or_else(|| {
+        freedesktop_icons::lookup(name)
+            .with_size(size)
+            .with_cache()
+            .find()
+    })
+}
+
+thread_local! {
+    /// Named icons which were already loaded, by their name, size, and icon theme.
+    static HANDLES: RefCell<HashMap<(String, u16, String), IconHandle>> =
+        RefCell::new(HashMap::new());
+}
+
+/// An icon which was loaded once, for widgets which draw icons themselves.
+#[derive(Debug, Clone)]
+pub enum IconHandle {
+    /// An SVG icon, which is tinted with the color of its text if it is symbolic.
+    Svg {
+        handle: svg::Handle,
+        is_symbolic: bool,
+    },
+    /// A raster icon.
+    Image(image::Handle),
+}
+
+impl IconHandle {
+    /// Loads an icon of the given size from its source.
+    ///
+    /// Embedded [`Image`] widgets do not expose their handle, and cannot be drawn by other
+    /// widgets, so they load nothing. Use [`IconSource::Image`] instead.
+    #[must_use]
+    pub fn from_source(source: IconSource<'_>, size: u16) -> Option<Self> {
+        match source {
+            IconSource::Name(name) => Some(Self::named(&name, size)),
+            IconSource::Path(path) => Some(Self::from_path(&path, false)),
+            IconSource::Svg(handle) => Some(Self::Svg {
+                handle,
+                is_symbolic: false,
+            }),
+            IconSource::Image(handle) => Some(Self::Image(handle)),
+            IconSource::Embedded(_) => None,
+        }
+    }
+
+    /// Loads a named icon from the default icon theme, which is empty if it is missing.
+    ///
+    /// Each icon is only looked up once, and shared by every widget that loads it.
+    #[must_use]
+    pub fn named(name: &str, size: u16) -> Self {
+        let key = (
+            name.to_owned(),
+            size,
+            crate::settings::DEFAULT_ICON_THEME.with(|theme| theme.borrow().clone()),
+        );
+
+        if let Some(handle) = HANDLES.with(|handles| handles.borrow().get(&key).cloned()) {
+            return handle;
+        }
+
+        let handle = match lookup(name, size, None) {
+            Some(path) => Self::from_path(&path, name.ends_with("-symbolic")),
+            None => Self::Svg {
+                handle: svg::Handle::from_memory(Vec::new()),
+                is_symbolic: false,
+            },
+        };
+
+        HANDLES.with(|handles| handles.borrow_mut().insert(key, handle.clone()));
+        handle
+    }
+
+    /// Loads an SVG or raster icon from a file, by its extension.
+    fn from_path(path: &Path, is_symbolic: bool) -> Self {
+        if path.extension() == Some(OsStr::new("svg")) {
+            Self::Svg {
+                handle: svg::Handle::from_path(path),
+                is_symbolic,
+            }
+        } else {
+            Self::Image(image::Handle::from_path(path))
+        }
+    }
+
+    /// Draws the icon within `bounds`, tinting symbolic icons with `color`.
+    pub(crate) fn draw<Renderer>(&self, renderer: &mut Renderer, bounds: Rectangle, color: Color)
+    where
+        Renderer: iced_native::svg::Renderer
+            + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
+    {
+        match self {
+            IconHandle::Svg {
+                handle,
+                is_symbolic,
+            } => iced_native::svg::Renderer::draw(
+                renderer,
+                handle.clone(),
+                if *is_symbolic { Some(color) } else { None },
+                bounds,
+            ),
+            IconHandle::Image(handle) => {
+                iced_native::image::Renderer::draw(renderer, handle.clone(), bounds);
+            }
+        }
+    }
+}
+
 impl<'a, Message: 'static> From<Icon<'a>> for Element<'a, Message> {
     fn from(icon: Icon<'a>) -> Self {
         icon.into_element::<Message>()
@@ -1,24 +1,60 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-use crate::widget::horizontal_rule;
-use crate::{theme, Element};
-use apply::Apply;
-use iced::{Background, Color};
+use crate::{theme, Element, Renderer};
+use iced::{Background, Color, Length, Point, Rectangle, Size};
+use iced_core::BorderRadius;
+use iced_native::event::{self, Event};
+use iced_native::widget::{tree, Operation, Tree};
+use iced_native::{keyboard, layout, mouse, overlay, renderer, touch, Renderer as _};
+use iced_native::{Clipboard, Layout, Shell, Widget};
+
+/// Height of the dividers drawn between rows.
+const DIVIDER_HEIGHT: f32 = 1.0;
+
+/// Height of the space between rows which their divider is centered in.
+const DIVIDER_SPACE: f32 = 10.0;
+
+/// Width of the drag handle drawn beside each row of a reorderable list.
+const HANDLE_WIDTH: f32 = 16.0;
+
+/// Height of the line which indicates where a dragged row will be dropped.
+const DROP_INDICATOR_HEIGHT: f32 = 2.0;
 
 #[must_use]
 pub fn list_column<'a, Message: 'static>() -> ListColumn<'a, Message> {
     ListColumn::default()
 }
 
+/// Whether one or many rows of a [`ListColumn`] may be selected at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Selecting a row replaces the selection, and the arrow keys select the next row.
+    #[default]
+    Single,
+    /// Selecting a row toggles it, and the arrow keys move focus without selecting.
+    Multiple,
+}
+
+/// A column of rows separated by dividers, whose rows may be selected and reordered.
 pub struct ListColumn<'a, Message> {
     children: Vec<Element<'a, Message>>,
+    selection_mode: SelectionMode,
+    selected: Vec<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    density: theme::Density,
 }
 
 impl<'a, Message: 'static> Default for ListColumn<'a, Message> {
     fn default() -> Self {
         Self {
             children: Vec::with_capacity(4),
+            selection_mode: SelectionMode::default(),
+            selected: Vec::new(),
+            on_select: None,
+            on_reorder: None,
+            density: theme::Density::default(),
         }
     }
 }
@@ -32,22 +68,575 @@ impl<'a, Message: 'static> ListColumn<'a, Message> {
     #[must_use]
     #[allow(clippy::should_implement_trait)]
     pub fn add(mut self, item: impl Into<Element<'a, Message>>) -> Self {
-        if !self.children.is_empty() {
-            self.children.push(horizontal_rule(10).into());
-        }
-
         self.children.push(item.into());
         self
     }
 
+    /// Whether one or many rows may be selected at once.
+    #[must_use]
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// The indices of the rows which are highlighted as selected.
+    #[must_use]
+    pub fn selected(mut self, selected: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = selected.into_iter().collect();
+        self
+    }
+
+    /// Makes the rows selectable, emitting the index of a row when it is selected.
+    ///
+    /// In [`SelectionMode::Multiple`], the application should toggle the row in its
+    /// selection, since a selected row may be selected again to deselect it.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Makes the rows reorderable by dragging their handles, or with Alt+Up and Alt+Down.
+    ///
+    /// Emits the index of the moved row, and the index that it should be moved to. The
+    /// application should remove the row and insert it again at the new index.
+    #[must_use]
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the density of the theme, which spaces the rows.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.density = density;
+        self
+    }
+
     #[must_use]
     pub fn into_element(self) -> Element<'a, Message> {
-        iced::widget::column(self.children)
-            .spacing(12)
-            .apply(iced::widget::container)
-            .padding([16, 6])
-            .style(theme::Container::Custom(style))
-            .into()
+        Element::new(self)
+    }
+
+    /// Width reserved before each child for its drag handle.
+    fn handle_space(&self) -> f32 {
+        if self.on_reorder.is_some() {
+            HANDLE_WIDTH + f32::from(self.density.tokens().spacing.s)
+        } else {
+            0.0
+        }
+    }
+
+    /// The bounds of the row around a child, which are highlighted when selected.
+    fn row_bounds(&self, child: Rectangle) -> Rectangle {
+        let tokens = self.density.tokens();
+        let horizontal = f32::from(tokens.padding.list[1]);
+        let vertical = f32::from(tokens.spacing.s);
+        let handle = self.handle_space();
+        Rectangle {
+            x: child.x - horizontal - handle,
+            y: child.y - vertical,
+            width: child.width + 2.0 * horizontal + handle,
+            height: child.height + 2.0 * vertical,
+        }
+    }
+
+    /// The bounds of the drag handle at the leading edge of a row.
+    fn handle_bounds(&self, row: Rectangle) -> Rectangle {
+        Rectangle {
+            x: row.x + f32::from(self.density.tokens().spacing.s),
+            width: HANDLE_WIDTH,
+            ..row
+        }
+    }
+
+    /// The index of the row under the cursor.
+    fn row_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        layout
+            .children()
+            .position(|child| self.row_bounds(child.bounds()).contains(cursor_position))
+    }
+
+    /// The index of the row whose drag handle is under the cursor.
+    fn handle_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        if self.on_reorder.is_none() {
+            return None;
+        }
+
+        layout.children().position(|child| {
+            self.handle_bounds(self.row_bounds(child.bounds()))
+                .contains(cursor_position)
+        })
+    }
+
+    /// The position between two rows where a row dragged to `y` would be inserted.
+    fn drop_position(&self, layout: Layout<'_>, y: f32) -> usize {
+        layout
+            .children()
+            .position(|child| {
+                let row = self.row_bounds(child.bounds());
+                y < row.y + row.height / 2.0
+            })
+            .unwrap_or(self.children.len())
+    }
+
+    /// Emits a reorder of a row to the position between rows that it was dropped at.
+    fn drop_row(&self, from: usize, position: usize, shell: &mut Shell<'_, Message>) {
+        // Removing the row shifts every row after it up by one.
+        let to = if position > from {
+            position - 1
+        } else {
+            position
+        };
+
+        if to != from {
+            if let Some(on_reorder) = self.on_reorder.as_ref() {
+                shell.publish(on_reorder(from, to));
+            }
+        }
+    }
+
+    /// Moves the keyboard focus to another row, and selects it in single selection mode.
+    fn focus_row(&self, state: &mut State, row: usize, shell: &mut Shell<'_, Message>) {
+        state.focused_row = Some(row);
+
+        if self.selection_mode == SelectionMode::Single && !self.selected.contains(&row) {
+            if let Some(on_select) = self.on_select.as_ref() {
+                shell.publish(on_select(row));
+            }
+        }
+    }
+}
+
+impl<'a, Message: 'static> Widget<Message, Renderer> for ListColumn<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let tokens = self.density.tokens();
+        let [vertical, horizontal] = tokens.padding.list.map(f32::from);
+        let row_spacing = f32::from(tokens.spacing.s);
+
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let max = limits.max();
+
+        let handle = self.handle_space();
+        let child_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new((max.width - 2.0 * horizontal - handle).max(0.0), max.height),
+        );
+
+        let mut y = vertical;
+        let nodes = self
+            .children
+            .iter()
+            .enumerate()
+            .map(|(nth, child)| {
+                if nth != 0 {
+                    y += 2.0 * row_spacing + DIVIDER_SPACE;
+                }
+
+                let mut node = child.as_widget().layout(renderer, &child_limits);
+                node.move_to(Point::new(horizontal + handle, y));
+                y += node.size().height;
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(max.width, y + vertical));
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(&self, tree: &mut Tree, layout: Layout<'_>, operation: &mut dyn Operation<Message>) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child.as_widget().operate(state, layout, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Dragging takes precedence over the rows, which should not react to the drag.
+        if let Some(drag) = state.drag.as_mut() {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    drag.position = self.drop_position(layout, cursor_position.y);
+                    return event::Status::Captured;
+                }
+
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    let (from, position) = (drag.row, drag.position);
+                    state.drag = None;
+                    state.focused_row = Some(from);
+                    self.drop_row(from, position, shell);
+                    return event::Status::Captured;
+                }
+
+                _ => (),
+            }
+        }
+
+        let status = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if status == event::Status::Captured
+            || (self.on_select.is_none() && self.on_reorder.is_none())
+        {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = layout.bounds().contains(cursor_position);
+
+                if let Some(row) = self.handle_at(layout, cursor_position) {
+                    state.focused_row = Some(row);
+                    state.drag = Some(Drag { row, position: row });
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(row) = self.row_at(layout, cursor_position) {
+                    state.focused_row = Some(row);
+
+                    if let Some(on_select) = self.on_select.as_ref() {
+                        shell.publish(on_select(row));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused => {
+                let last = match self.children.len().checked_sub(1) {
+                    Some(last) => last,
+                    None => return event::Status::Ignored,
+                };
+
+                let current = state
+                    .focused_row
+                    .or_else(|| self.selected.first().copied())
+                    .filter(|&row| row <= last);
+
+                // Alt+Up and Alt+Down move the focused row, as an alternative to dragging.
+                if modifiers.alt() {
+                    if let (Some(row), Some(on_reorder)) = (current, self.on_reorder.as_ref()) {
+                        let to = match key_code {
+                            keyboard::KeyCode::Up => row.saturating_sub(1),
+                            keyboard::KeyCode::Down => (row + 1).min(last),
+                            _ => return event::Status::Ignored,
+                        };
+
+                        if to != row {
+                            state.focused_row = Some(to);
+                            shell.publish(on_reorder(row, to));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                let row = match key_code {
+                    keyboard::KeyCode::Up => current.map_or(last, |row| row.saturating_sub(1)),
+                    keyboard::KeyCode::Down => current.map_or(0, |row| (row + 1).min(last)),
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    keyboard::KeyCode::Space | keyboard::KeyCode::Enter => {
+                        if let (Some(row), Some(on_select)) = (current, self.on_select.as_ref()) {
+                            if self.selection_mode == SelectionMode::Multiple {
+                                shell.publish(on_select(row));
+                                return event::Status::Captured;
+                            }
+                        }
+
+                        return event::Status::Ignored;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.focus_row(state, row, shell);
+                return event::Status::Captured;
+            }
+
+            _ => (),
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if self.handle_at(layout, cursor_position).is_some() {
+            return mouse::Interaction::Grab;
+        }
+
+        let interaction = self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::Idle
+            && self.on_select.is_some()
+            && self.row_at(layout, cursor_position).is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = self::style(theme);
+        let cosmic = theme.cosmic();
+        let tokens = theme.tokens();
+        let radius = tokens.radius.m;
+        let inset = f32::from(tokens.padding.list[1]);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: BorderRadius::from(appearance.border_radius),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        let text_color = appearance.text_color.unwrap_or(style.text_color);
+        let mut rows = Vec::with_capacity(self.children.len());
+
+        for (nth, ((child, tree), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let row = self.row_bounds(layout.bounds());
+            rows.push(row);
+
+            // Draw the divider above every row but the first.
+            if nth != 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: row.x + inset,
+                            y: row.y - (DIVIDER_SPACE + DIVIDER_HEIGHT) / 2.0,
+                            width: (row.width - 2.0 * inset).max(0.0),
+                            height: DIVIDER_HEIGHT,
+                        },
+                        border_radius: BorderRadius::from(0.0),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Background::Color(cosmic.primary.divider.into()),
+                );
+            }
+
+            let is_selected = self.selected.contains(&nth);
+            let is_dragged = state.drag.map_or(false, |drag| drag.row == nth);
+            let is_hovered = is_dragged
+                || (state.drag.is_none()
+                    && self.on_select.is_some()
+                    && row.contains(cursor_position));
+
+            let background = if is_selected {
+                Some(cosmic.primary.component.selected)
+            } else if is_hovered {
+                Some(cosmic.primary.component.hover)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        border_radius: BorderRadius::from(radius),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Background::Color(background.into()),
+                );
+            }
+
+            // Outline the row that the keyboard is focused on.
+            if state.is_focused && state.focused_row == Some(nth) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        border_radius: BorderRadius::from(radius),
+                        border_width: if theme.is_high_contrast() { 3.0 } else { 2.0 },
+                        border_color: cosmic.accent.focus.into(),
+                    },
+                    Background::Color(Color::TRANSPARENT),
+                );
+            }
+
+            let text_color = if is_selected {
+                cosmic.primary.component.selected_text.into()
+            } else {
+                text_color
+            };
+
+            if self.on_reorder.is_some() {
+                draw_handle(renderer, self.handle_bounds(row), text_color);
+            }
+
+            child.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style { text_color },
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        // Draw a line in the gap between the rows where the dragged row will be dropped.
+        // Dropping a row beside itself would not move it, so nothing is indicated there.
+        if let Some(drag) = state
+            .drag
+            .filter(|drag| drag.position != drag.row && drag.position != drag.row + 1)
+        {
+            let below = rows.get(drag.position);
+            let y = match drag
+                .position
+                .checked_sub(1)
+                .and_then(|above| rows.get(above))
+            {
+                Some(above) => {
+                    let bottom = above.y + above.height;
+                    below.map_or(bottom, |below| (bottom + below.y) / 2.0)
+                }
+                None => match below {
+                    Some(below) => below.y,
+                    None => return,
+                },
+            };
+
+            if let Some(first) = rows.first() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: first.x,
+                            y: y - DROP_INDICATOR_HEIGHT / 2.0,
+                            width: first.width,
+                            height: DROP_INDICATOR_HEIGHT,
+                        },
+                        border_radius: BorderRadius::from(DROP_INDICATOR_HEIGHT / 2.0),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Background::Color(cosmic.accent.base.into()),
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&self.children, tree, layout, renderer)
     }
 }
 
@@ -57,6 +646,51 @@ impl<'a, Message: 'static> From<ListColumn<'a, Message>> for Element<'a, Message
     }
 }
 
+/// State that is maintained by each list column.
+#[derive(Default)]
+struct State {
+    /// Whether the list receives keyboard input, after one of its rows was clicked.
+    is_focused: bool,
+    /// The row which the keyboard navigates from.
+    focused_row: Option<usize>,
+    /// The row that is being dragged by its handle.
+    drag: Option<Drag>,
+}
+
+/// A row being dragged to a new position.
+#[derive(Clone, Copy)]
+struct Drag {
+    /// The index of the dragged row.
+    row: usize,
+    /// The position between rows where the row would be dropped, from `0` to the row count.
+    position: usize,
+}
+
+/// Draws the grip of a drag handle as three short horizontal lines.
+fn draw_handle(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
+    const LINE_HEIGHT: f32 = 2.0;
+    const LINE_GAP: f32 = 3.0;
+
+    let top = bounds.center_y() - (3.0 * LINE_HEIGHT + 2.0 * LINE_GAP) / 2.0;
+
+    for line in 0..3 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 2.0,
+                    y: top + line as f32 * (LINE_HEIGHT + LINE_GAP),
+                    width: bounds.width - 4.0,
+                    height: LINE_HEIGHT,
+                },
+                border_radius: BorderRadius::from(LINE_HEIGHT / 2.0),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Background::Color(Color { a: 0.6, ..color }),
+        );
+    }
+}
+
 #[must_use]
 #[allow(clippy::trivially_copy_pass_by_ref)]
 pub fn style(theme: &crate::Theme) -> iced::widget::container::Appearance {
@@ -64,7 +698,7 @@ pub fn style(theme: &crate::Theme) -> iced::widget::container::Appearance {
     iced::widget::container::Appearance {
         text_color: Some(cosmic.on.into()),
         background: Some(Background::Color(cosmic.base.into())),
-        border_radius: 8.0,
+        border_radius: theme.tokens().radius.m,
         border_width: 0.0,
         border_color: Color::TRANSPARENT,
     }
@@ -1,2 +1,162 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
+
+use crate::widget::{icon, text, IconSource};
+use crate::{theme, Element, Theme};
+use apply::Apply;
+use derive_setters::Setters;
+use iced::widget::{button, column, container, horizontal_space, row};
+use iced::{Alignment, Background, Color, Length};
+use std::borrow::Cow;
+
+/// A row of a list, with a title and optional leading, subtitle, and trailing content.
+#[must_use]
+pub fn list_item<'a, Message>(title: impl Into<Cow<'a, str>>) -> ListItem<'a, Message> {
+    ListItem {
+        title: title.into(),
+        subtitle: None,
+        leading: None,
+        trailing: None,
+        on_press: None,
+        selected: false,
+        density: theme::Density::default(),
+    }
+}
+
+/// A row of a list, with a title and optional leading, subtitle, and trailing content.
+#[derive(Setters)]
+pub struct ListItem<'a, Message> {
+    #[setters(skip)]
+    title: Cow<'a, str>,
+    /// Text shown beneath the title.
+    #[setters(strip_option, into)]
+    subtitle: Option<Cow<'a, str>>,
+    /// A widget shown before the title, such as an icon or avatar.
+    #[setters(strip_option, into)]
+    leading: Option<Element<'a, Message>>,
+    /// A widget shown at the end of the row, such as a toggler or button.
+    #[setters(strip_option, into)]
+    trailing: Option<Element<'a, Message>>,
+    /// The message to emit when the row is pressed.
+    #[setters(strip_option)]
+    on_press: Option<Message>,
+    /// Whether the row is highlighted as selected.
+    selected: bool,
+    /// The density of the theme, which pads the row.
+    density: theme::Density,
+}
+
+impl<'a, Message: 'static> ListItem<'a, Message> {
+    /// Shows a symbolic icon before the title.
+    #[must_use]
+    pub fn icon(mut self, name: impl Into<IconSource<'a>>) -> Self {
+        self.leading = Some(icon(name, 24).style(theme::Svg::Symbolic).into());
+        self
+    }
+
+    #[must_use]
+    pub fn into_element(self) -> Element<'a, Message> {
+        let tokens = self.density.tokens();
+        let mut children: Vec<Element<'a, Message>> = Vec::with_capacity(4);
+
+        if let Some(leading) = self.leading {
+            children.push(leading);
+        }
+
+        let mut label = vec![text::body(self.title).into()];
+        if let Some(subtitle) = self.subtitle {
+            label.push(text::caption(subtitle).into());
+        }
+
+        children.push(column(label).into());
+        children.push(horizontal_space(Length::Fill).into());
+
+        if let Some(trailing) = self.trailing {
+            children.push(trailing);
+        }
+
+        let content = row(children)
+            .align_items(Alignment::Center)
+            .spacing(tokens.spacing.s)
+            .width(Length::Fill);
+
+        if let Some(message) = self.on_press {
+            content
+                .apply(button)
+                .padding([tokens.spacing.xs, tokens.spacing.s])
+                .width(Length::Fill)
+                .style(if self.selected {
+                    theme::Button::Custom {
+                        active: selected_style,
+                        hover: selected_style,
+                    }
+                } else {
+                    theme::Button::Custom {
+                        active: active_style,
+                        hover: hover_style,
+                    }
+                })
+                .on_press(message)
+                .into()
+        } else {
+            content
+                .apply(container)
+                .padding([tokens.spacing.xs, tokens.spacing.s])
+                .width(Length::Fill)
+                .style(if self.selected {
+                    theme::Container::Custom(selected_container_style)
+                } else {
+                    theme::Container::Transparent
+                })
+                .into()
+        }
+    }
+}
+
+impl<'a, Message: 'static> From<ListItem<'a, Message>> for Element<'a, Message> {
+    fn from(item: ListItem<'a, Message>) -> Self {
+        item.into_element()
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn active_style(theme: &Theme) -> button::Appearance {
+    button::Appearance {
+        background: None,
+        border_radius: theme.tokens().radius.m.into(),
+        text_color: theme.cosmic().primary.on.into(),
+        ..button::Appearance::default()
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn hover_style(theme: &Theme) -> button::Appearance {
+    button::Appearance {
+        background: Some(Background::Color(
+            theme.cosmic().primary.component.hover.into(),
+        )),
+        ..active_style(theme)
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn selected_style(theme: &Theme) -> button::Appearance {
+    let component = &theme.cosmic().primary.component;
+    button::Appearance {
+        background: Some(Background::Color(component.selected.into())),
+        text_color: component.selected_text.into(),
+        ..active_style(theme)
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn selected_container_style(theme: &Theme) -> container::Appearance {
+    let component = &theme.cosmic().primary.component;
+    container::Appearance {
+        text_color: Some(component.selected_text.into()),
+        background: Some(Background::Color(component.selected.into())),
+        border_radius: theme.tokens().radius.m,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }
+}
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: MPL-2.0
 
 pub mod column;
-// mod item;
+pub mod item;
+pub mod virtualized;
 
-pub use self::column::{list_column, ListColumn};
-// pub use self::item::{ListItem, list_item};
+pub use self::column::{list_column, ListColumn, SelectionMode};
+pub use self::item::{list_item, ListItem};
+pub use self::virtualized::{virtual_list, RowHeight, VirtualList};
@@ -0,0 +1,670 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A scrollable list which only creates the rows that are visible.
+
+use crate::{theme, Element, Renderer};
+use iced::{Background, Length, Point, Rectangle, Size};
+use iced_core::BorderRadius;
+use iced_native::event::{self, Event};
+use iced_native::widget::{tree, Operation, Tree};
+use iced_native::{layout, mouse, overlay, renderer, Renderer as _};
+use iced_native::{Clipboard, Layout, Shell, Widget};
+use iced_style::scrollable::StyleSheet;
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+/// Width of the scrollbar and its scroller.
+const SCROLLBAR_WIDTH: f32 = 8.0;
+
+/// Distance that a line of mouse wheel scrolling moves the list.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// The height of each row of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowHeight {
+    /// Every row has exactly this height.
+    Fixed(u16),
+    /// Rows have about this height, and are laid out with their own heights when visible.
+    ///
+    /// Rows which have not been laid out yet are assumed to have this height, so it
+    /// should be close to the average height of a row.
+    Estimated(u16),
+}
+
+impl RowHeight {
+    fn height(self) -> f32 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Estimated(height) => f32::from(height).max(1.0),
+        }
+    }
+}
+
+/// A scrollable list of `row_count` rows, which are created by `view_row` when visible.
+///
+/// The list should be given a bounded height, and must not be placed in a scrollable.
+#[must_use]
+pub fn virtual_list<'a, Message>(
+    row_count: usize,
+    row_height: RowHeight,
+    view_row: impl Fn(usize) -> Element<'a, Message> + 'a,
+) -> VirtualList<'a, Message> {
+    VirtualList {
+        row_count,
+        row_height,
+        view_row: Box::new(view_row),
+        width: Length::Fill,
+        height: Length::Fill,
+        style: theme::Scrollable::Gutter,
+        offset: Cell::new(0.0),
+        heights: RefCell::new(Heights::default()),
+        rows: RefCell::new(Vec::new()),
+    }
+}
+
+/// The heights that rows of estimated height were laid out with, by the index of their row.
+type Heights = Rc<RefCell<BTreeMap<usize, f32>>>;
+
+/// A scrollable list which only creates the rows that are visible.
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message> {
+    row_count: usize,
+    row_height: RowHeight,
+    view_row: Box<dyn Fn(usize) -> Element<'a, Message> + 'a>,
+    width: Length,
+    height: Length,
+    style: theme::Scrollable,
+    /// The scroll position, which is copied from the tree before each layout.
+    offset: Cell<f32>,
+    /// The laid out heights of rows, which are shared with the tree.
+    heights: RefCell<Heights>,
+    /// The visible rows, which are created during layout.
+    rows: RefCell<Vec<Row<'a, Message>>>,
+}
+
+/// A visible row of a [`VirtualList`].
+struct Row<'a, Message> {
+    index: usize,
+    element: Element<'a, Message>,
+    /// Whether the tree of the row has been diffed with this element.
+    is_diffed: Cell<bool>,
+}
+
+impl<'a, Message> VirtualList<'a, Message> {
+    /// Sets the width of the list.
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the list.
+    #[must_use]
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the scrollbar.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<theme::Scrollable>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// The laid out heights of rows, shared with the tree.
+    fn heights(&self) -> Heights {
+        Rc::clone(&self.heights.borrow())
+    }
+
+    /// The height of every row, where rows which were not laid out have the estimated height.
+    fn content_height(&self) -> f32 {
+        let row_height = self.row_height.height();
+        let estimated = self.row_count as f32 * row_height;
+
+        match self.row_height {
+            RowHeight::Fixed(_) => estimated,
+            RowHeight::Estimated(_) => {
+                let heights = self.heights();
+                let heights = heights.borrow();
+                estimated
+                    + heights
+                        .range(..self.row_count)
+                        .map(|(_, height)| height - row_height)
+                        .sum::<f32>()
+            }
+        }
+    }
+
+    /// The index of the row at a scroll position, and the distance from the top to the row.
+    fn row_at_offset(&self, heights: &BTreeMap<usize, f32>, offset: f32) -> (usize, f32) {
+        let row_height = self.row_height.height();
+        let (mut index, mut top) = (0, 0.0);
+
+        if let RowHeight::Estimated(_) = self.row_height {
+            for (&laid_out, &height) in heights.range(..self.row_count) {
+                // The rows between rows that were laid out have the estimated height.
+                let laid_out_top = top + (laid_out - index) as f32 * row_height;
+                if offset < laid_out_top {
+                    break;
+                }
+
+                if offset < laid_out_top + height {
+                    return (laid_out, laid_out_top);
+                }
+
+                index = laid_out + 1;
+                top = laid_out_top + height;
+            }
+        }
+
+        let skipped = ((offset - top) / row_height).floor() as usize;
+        (index + skipped, top + skipped as f32 * row_height)
+    }
+
+    /// Limits a scroll position to the content within the bounds.
+    fn clamp_offset(&self, offset: f32, bounds: Rectangle) -> f32 {
+        offset.min(self.content_height() - bounds.height).max(0.0)
+    }
+
+    /// Scrolls to a position, and lays out the rows which become visible.
+    fn scroll_to(
+        &self,
+        state: &mut State,
+        offset: f32,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let offset = self.clamp_offset(offset, bounds);
+
+        if (offset - state.offset).abs() > f32::EPSILON {
+            state.offset = offset;
+            self.offset.set(offset);
+            shell.invalidate_layout();
+        }
+    }
+
+    /// The bounds of the scrollbar track, and of its scroller.
+    fn scrollbar(&self, bounds: Rectangle, offset: f32) -> Option<(Rectangle, Rectangle)> {
+        let content_height = self.content_height();
+        if content_height <= bounds.height {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: bounds.x + bounds.width - SCROLLBAR_WIDTH,
+            width: SCROLLBAR_WIDTH,
+            ..bounds
+        };
+
+        let height = (bounds.height * bounds.height / content_height).max(SCROLLBAR_WIDTH * 2.0);
+        let scroller = Rectangle {
+            y: track.y + (track.height - height) * offset / (content_height - bounds.height),
+            height,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+
+    /// The scroll position at which the top of the scroller is at `y`.
+    fn offset_at(&self, bounds: Rectangle, track: Rectangle, scroller: Rectangle, y: f32) -> f32 {
+        let range = track.height - scroller.height;
+        if range <= 0.0 {
+            return 0.0;
+        }
+
+        (y - track.y) / range * (self.content_height() - bounds.height)
+    }
+
+    /// Creates and diffs the trees of rows which were created since the last event.
+    fn sync_trees(&self, trees: &mut HashMap<usize, Tree>) {
+        let rows = self.rows.borrow();
+        trees.retain(|index, _| rows.iter().any(|row| row.index == *index));
+
+        for row in rows.iter() {
+            let tree = trees
+                .entry(row.index)
+                .or_insert_with(|| Tree::new(&row.element));
+
+            if !row.is_diffed.replace(true) {
+                tree.diff(&row.element);
+            }
+        }
+    }
+}
+
+impl<'a, Message: 'a> Widget<Message, Renderer> for VirtualList<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            heights: self.heights(),
+            ..State::default()
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_ref::<State>();
+        self.offset.set(state.offset);
+        self.heights.replace(Rc::clone(&state.heights));
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+        let bounds = Rectangle::new(Point::ORIGIN, size);
+
+        let row_height = self.row_height.height();
+        let offset = self.clamp_offset(self.offset.get(), bounds);
+
+        let heights = self.heights();
+        let mut heights = heights.borrow_mut();
+        heights.retain(|&index, _| index < self.row_count);
+        let (first, top) = self.row_at_offset(&heights, offset);
+
+        let width = (size.width - SCROLLBAR_WIDTH).max(0.0);
+        let row_limits = match self.row_height {
+            RowHeight::Fixed(_) => {
+                layout::Limits::new(Size::new(0.0, row_height), Size::new(width, row_height))
+            }
+            RowHeight::Estimated(_) => {
+                layout::Limits::new(Size::ZERO, Size::new(width, size.height.max(row_height)))
+            }
+        };
+
+        // Reuse rows which remain visible, and create the rest until the list is filled.
+        let mut rows = self.rows.borrow_mut();
+        let mut previous = std::mem::take(&mut *rows);
+        let mut nodes = Vec::new();
+        let mut y = top - offset;
+
+        for index in first..self.row_count {
+            if y >= size.height {
+                break;
+            }
+
+            let row = match previous.iter().position(|row| row.index == index) {
+                Some(position) => previous.swap_remove(position),
+                None => Row {
+                    index,
+                    element: (self.view_row)(index),
+                    is_diffed: Cell::new(false),
+                },
+            };
+
+            let mut node = row.element.as_widget().layout(renderer, &row_limits);
+            node.move_to(Point::new(0.0, y));
+
+            y += match self.row_height {
+                RowHeight::Fixed(_) => row_height,
+                RowHeight::Estimated(_) => {
+                    heights.insert(index, node.size().height);
+                    node.size().height
+                }
+            };
+
+            rows.push(row);
+            nodes.push(node);
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(&self, tree: &mut Tree, layout: Layout<'_>, operation: &mut dyn Operation<Message>) {
+        let trees = tree.state.downcast_mut::<State>().trees.get_mut();
+        self.sync_trees(trees);
+
+        operation.container(None, &mut |operation| {
+            for (row, layout) in self.rows.borrow().iter().zip(layout.children()) {
+                if let Some(tree) = trees.get_mut(&row.index) {
+                    row.element.as_widget().operate(tree, layout, operation);
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        // Rows which are scrolled out of view should not react to the cursor.
+        let row_cursor = if bounds.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        self.sync_trees(state.trees.get_mut());
+
+        let trees = state.trees.get_mut();
+        let status = self
+            .rows
+            .get_mut()
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(row, layout)| {
+                let tree = trees.get_mut(&row.index)?;
+                Some(row.element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    row_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                ))
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * LINE_HEIGHT,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                let offset = state.offset - delta;
+                self.scroll_to(state, offset, bounds, shell);
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some((track, scroller)) = self.scrollbar(bounds, state.offset) {
+                    if scroller.contains(cursor_position) {
+                        state.scroller_grabbed_at = Some(cursor_position.y - scroller.y);
+                        return event::Status::Captured;
+                    }
+
+                    // Clicking the track moves the scroller to the cursor.
+                    if track.contains(cursor_position) {
+                        let grabbed_at = scroller.height / 2.0;
+                        state.scroller_grabbed_at = Some(grabbed_at);
+                        let offset =
+                            self.offset_at(bounds, track, scroller, cursor_position.y - grabbed_at);
+                        self.scroll_to(state, offset, bounds, shell);
+                        return event::Status::Captured;
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grabbed_at) = state.scroller_grabbed_at {
+                    if let Some((track, scroller)) = self.scrollbar(bounds, state.offset) {
+                        let offset =
+                            self.offset_at(bounds, track, scroller, position.y - grabbed_at);
+                        self.scroll_to(state, offset, bounds, shell);
+                        return event::Status::Captured;
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.scroller_grabbed_at.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => (),
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        if !bounds.contains(cursor_position) {
+            return mouse::Interaction::Idle;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let mut trees = state.trees.borrow_mut();
+        self.sync_trees(&mut trees);
+
+        self.rows
+            .borrow()
+            .iter()
+            .zip(layout.children())
+            .filter_map(|(row, layout)| {
+                let tree = trees.get(&row.index)?;
+                Some(row.element.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    cursor_position,
+                    &bounds.intersection(viewport).unwrap_or(bounds),
+                    renderer,
+                ))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let mut trees = state.trees.borrow_mut();
+        self.sync_trees(&mut trees);
+
+        let row_cursor = if bounds.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        let visible = bounds.intersection(viewport).unwrap_or(bounds);
+
+        renderer.with_layer(bounds, |renderer| {
+            for (row, layout) in self.rows.borrow().iter().zip(layout.children()) {
+                if let Some(tree) = trees.get(&row.index) {
+                    row.element
+                        .as_widget()
+                        .draw(tree, renderer, theme, style, layout, row_cursor, &visible);
+                }
+            }
+        });
+
+        if let Some((track, scroller)) = self.scrollbar(bounds, state.offset) {
+            let appearance = if state.scroller_grabbed_at.is_some() {
+                StyleSheet::dragging(theme, &self.style)
+            } else if track.contains(cursor_position) {
+                StyleSheet::hovered(theme, &self.style)
+            } else {
+                StyleSheet::active(theme, &self.style)
+            };
+
+            if let Some(background) = appearance.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track,
+                        border_radius: BorderRadius::from(appearance.border_radius),
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: scroller,
+                    border_radius: BorderRadius::from(appearance.scroller.border_radius),
+                    border_width: appearance.scroller.border_width,
+                    border_color: appearance.scroller.border_color,
+                },
+                Background::Color(appearance.scroller.color),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let trees = tree.state.downcast_mut::<State>().trees.get_mut();
+        self.sync_trees(trees);
+
+        let overlay = RowsOverlayBuilder {
+            rows: self.rows.borrow(),
+            trees,
+            overlay_builder: |rows, trees| {
+                // The trees are synced with the rows, which are in the order of their index.
+                let mut trees: Vec<_> = trees.iter_mut().collect();
+                trees.sort_unstable_by_key(|(index, _)| **index);
+
+                let children: Vec<_> = rows
+                    .iter()
+                    .zip(trees)
+                    .zip(layout.children())
+                    .filter_map(|((row, (_, tree)), layout)| {
+                        row.element.as_widget().overlay(tree, layout, renderer)
+                    })
+                    .collect();
+
+                if children.is_empty() {
+                    None
+                } else {
+                    Some(overlay::Group::with_children(children).overlay())
+                }
+            },
+        }
+        .build();
+
+        if overlay.with_overlay(Option::is_some) {
+            Some(overlay::Element::new(Point::ORIGIN, Box::new(overlay)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<VirtualList<'a, Message>> for Element<'a, Message> {
+    fn from(list: VirtualList<'a, Message>) -> Self {
+        Self::new(list)
+    }
+}
+
+/// State that is maintained by each virtual list.
+#[derive(Default)]
+struct State {
+    /// The distance that the list is scrolled from the top.
+    offset: f32,
+    /// Where the scroller was grabbed, relative to its top, while it is dragged.
+    scroller_grabbed_at: Option<f32>,
+    /// The trees of the visible rows, by the index of their row.
+    trees: RefCell<HashMap<usize, Tree>>,
+    /// The laid out heights of rows, which are shared with each rebuilt list.
+    heights: Heights,
+}
+
+/// The overlays of the visible rows, which borrow the rows that were created in layout.
+#[ouroboros::self_referencing]
+struct RowsOverlay<'a, 'b, Message> {
+    rows: Ref<'a, Vec<Row<'b, Message>>>,
+    trees: &'a mut HashMap<usize, Tree>,
+    #[borrows(rows, mut trees)]
+    #[not_covariant]
+    overlay: Option<overlay::Element<'this, Message, Renderer>>,
+}
+
+impl<'a, 'b, Message> overlay::Overlay<Message, Renderer> for RowsOverlay<'a, 'b, Message> {
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+        self.with_overlay(|overlay| {
+            overlay.as_ref().map(|overlay| {
+                let translation = position - overlay.position();
+                overlay.layout(renderer, bounds).translate(translation)
+            })
+        })
+        .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.with_overlay(|overlay| {
+            if let Some(overlay) = overlay {
+                overlay.draw(renderer, theme, style, layout, cursor_position);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.with_overlay(|overlay| {
+            overlay
+                .as_ref()
+                .map_or_else(mouse::Interaction::default, |overlay| {
+                    overlay.mouse_interaction(layout, cursor_position, viewport, renderer)
+                })
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.with_overlay_mut(|overlay| {
+            overlay.as_mut().map_or(event::Status::Ignored, |overlay| {
+                overlay.on_event(event, layout, cursor_position, renderer, clipboard, shell)
+            })
+        })
+    }
+}
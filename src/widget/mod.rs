@@ -4,11 +4,14 @@
 mod button;
 pub use button::*;
 
+pub mod expander;
+pub use expander::{expander, Expander};
+
 mod header_bar;
 pub use header_bar::{header_bar, HeaderBar};
 
 mod icon;
-pub use self::icon::{icon, Icon, IconSource};
+pub use self::icon::{icon, Icon, IconHandle, IconSource};
 
 pub mod list;
 pub use self::list::*;
@@ -38,6 +41,14 @@ pub use separator::{horizontal_rule, vertical_rule};
 pub mod spin_button;
 pub use spin_button::{spin_button, SpinButton};
 
+pub mod table;
+pub use table::{table, SortOrder, Table, TableColumn};
+
+pub mod text;
+
+pub mod tree_view;
+pub use tree_view::{tree_view, TreeView};
+
 pub mod rectangle_tracker;
 
 pub mod aspect_ratio;
@@ -36,7 +36,7 @@ pub mod nav_bar {
         widget::container::Appearance {
             text_color: Some(cosmic.on.into()),
             background: Some(Background::Color(cosmic.base.into())),
-            border_radius: 8.0,
+            border_radius: theme.tokens().radius.m,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
         }
@@ -48,7 +48,7 @@ pub mod nav_bar {
         widget::container::Appearance {
             text_color: Some(primary.on.into()),
             background: Some(Background::Color(secondary.component.base.into())),
-            border_radius: 8.0,
+            border_radius: theme.tokens().radius.m,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
         }
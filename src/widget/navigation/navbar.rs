@@ -2,13 +2,14 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use crate::widget::nav_bar::{nav_bar_pages_style, nav_bar_sections_style};
+use crate::widget::text::{body, caption};
 use crate::widget::{icon, scrollable};
 use crate::{theme, Renderer, Theme};
 use derive_setters::Setters;
 use iced::{Background, Length};
 use iced_core::BorderRadius;
 use iced_lazy::Component;
-use iced_native::widget::{button, column, container, text};
+use iced_native::widget::{button, column, container};
 use iced_native::{row, Alignment, Element};
 use iced_style::button::Appearance;
 use std::collections::BTreeMap;
@@ -135,7 +136,7 @@ impl<'a, Message> Component<Message, Renderer> for NavBar<'a, Message> {
                     button(
                         column(vec![
                             icon(section.icon.clone(), 20).into(),
-                            text(section.title.clone()).size(14).into(),
+                            caption(section.title.clone()).into(),
                         ])
                         .width(Length::Units(100))
                         .height(Length::Units(50))
@@ -154,7 +155,7 @@ impl<'a, Message> Component<Message, Renderer> for NavBar<'a, Message> {
                 if *section == state.selected_section {
                     for page in section_pages {
                         pages.push(
-                            button(row![text(&page.title).size(16).width(Length::Fill)])
+                            button(row![body(&page.title).width(Length::Fill)])
                                 .padding(10)
                                 .style(if let Some(selected_page) = &state.selected_page {
                                     if state.page_active && page == selected_page {
@@ -1,13 +1,29 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-use crate::{Element, Renderer};
+use crate::{theme, Element, Renderer};
 use iced::widget;
 
+/// A scrollable with its scrollbar drawn within a visible track.
 pub fn scrollable<'a, Message>(
     element: impl Into<Element<'a, Message>>,
 ) -> widget::Scrollable<'a, Message, Renderer> {
     widget::scrollable(element)
         .scrollbar_width(8)
         .scroller_width(8)
+        .style(theme::Scrollable::Gutter)
+}
+
+/// A scrollable with a thin scrollbar drawn over its content.
+///
+/// The scrollbar has no track, and its scroller appears while the pointer is over the
+/// content, and is highlighted while the pointer is over the scrollbar or the scroller is
+/// being dragged. It is always shown in high contrast themes.
+pub fn overlay_scrollable<'a, Message>(
+    element: impl Into<Element<'a, Message>>,
+) -> widget::Scrollable<'a, Message, Renderer> {
+    widget::scrollable(element)
+        .scrollbar_width(4)
+        .scroller_width(4)
+        .style(theme::Scrollable::Overlay)
 }
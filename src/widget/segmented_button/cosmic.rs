@@ -2,19 +2,23 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use super::{HorizontalSegmentedButton, SegmentedButton, State, VerticalSegmentedButton};
+use crate::theme::Tokens;
 
 /// Appears as a collection of tabs for developing a tabbed interface.
 ///
 /// The data for the widget comes from a [`State`] that is maintained the application.
+///
+/// Ctrl+Tab, Ctrl+Shift+Tab, Ctrl+Page Down, and Ctrl+Page Up switch tabs while the view
+/// switcher is focused, or from anywhere in the window with
+/// [`global_shortcuts`](SegmentedButton::global_shortcuts).
 #[must_use]
 pub fn horizontal_view_switcher<Message, Data>(
     state: &State<Data>,
 ) -> HorizontalSegmentedButton<Message, crate::Renderer> {
     SegmentedButton::new(&state.inner)
-        .button_padding([16, 0, 16, 0])
-        .button_height(48)
+        .button_size(tab_size)
         .style(crate::theme::SegmentedButton::ViewSwitcher)
-        .font_active(crate::font::FONT_SEMIBOLD)
+        .font_active(crate::font::semibold())
 }
 
 /// Appears as a selection of choices for choosing between.
@@ -25,10 +29,9 @@ pub fn horizontal_segmented_selection<Message, Data>(
     state: &State<Data>,
 ) -> HorizontalSegmentedButton<Message, crate::Renderer> {
     SegmentedButton::new(&state.inner)
-        .button_padding([16, 0, 16, 0])
-        .button_height(32)
+        .button_size(control_size)
         .style(crate::theme::SegmentedButton::Selection)
-        .font_active(crate::font::FONT_SEMIBOLD)
+        .font_active(crate::font::semibold())
 }
 
 /// Appears as a selection of choices for choosing between.
@@ -39,22 +42,36 @@ pub fn vertical_segmented_selection<Message, Data>(
     state: &State<Data>,
 ) -> VerticalSegmentedButton<Message, crate::Renderer> {
     SegmentedButton::new(&state.inner)
-        .button_padding([16, 0, 16, 0])
-        .button_height(32)
+        .button_size(control_size)
         .style(crate::theme::SegmentedButton::Selection)
-        .font_active(crate::font::FONT_SEMIBOLD)
+        .font_active(crate::font::semibold())
 }
 
 /// Appears as a collection of tabs for developing a tabbed interface.
 ///
 /// The data for the widget comes from a [`State`] that is maintained the application.
+///
+/// Ctrl+Tab, Ctrl+Shift+Tab, Ctrl+Page Down, and Ctrl+Page Up switch tabs while the view
+/// switcher is focused, or from anywhere in the window with
+/// [`global_shortcuts`](SegmentedButton::global_shortcuts).
 #[must_use]
 pub fn vertical_view_switcher<Message, Data>(
     state: &State<Data>,
 ) -> VerticalSegmentedButton<Message, crate::Renderer> {
     SegmentedButton::new(&state.inner)
-        .button_padding([16, 0, 16, 0])
-        .button_height(48)
+        .button_size(tab_size)
         .style(crate::theme::SegmentedButton::ViewSwitcher)
-        .font_active(crate::font::FONT_SEMIBOLD)
+        .font_active(crate::font::semibold())
+}
+
+/// Pads and sizes the buttons of a view switcher as tabs.
+fn tab_size(tokens: &Tokens) -> ([u16; 4], u16) {
+    let padding = tokens.spacing.m;
+    ([padding, 0, padding, 0], tokens.size.tab)
+}
+
+/// Pads and sizes the buttons of a segmented selection as controls.
+fn control_size(tokens: &Tokens) -> ([u16; 4], u16) {
+    let padding = tokens.spacing.m;
+    ([padding, 0, padding, 0], tokens.size.control)
 }
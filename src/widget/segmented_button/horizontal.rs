@@ -1,8 +1,8 @@
 use super::state::State;
 use super::style::StyleSheet;
-use super::widget::{SegmentedButton, SegmentedVariant};
+use super::widget::{SegmentedButton, SegmentedVariant, DROP_INDICATOR_WIDTH};
 
-use iced::{Length, Rectangle, Size};
+use iced::{Length, Point, Rectangle, Size};
 use iced_native::layout;
 
 /// A type marker defining the horizontal variant of a [`SegmentedButton`].
@@ -18,7 +18,10 @@ pub fn horizontal_segmented_button<Message, Renderer, Data>(
     state: &State<Data>,
 ) -> SegmentedButton<Horizontal, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
 {
     SegmentedButton::new(&state.inner)
@@ -26,7 +29,10 @@ where
 
 impl<'a, Message, Renderer> SegmentedVariant for SegmentedButton<'a, Horizontal, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
 {
     type Renderer = Renderer;
@@ -40,7 +46,19 @@ where
 
     #[allow(clippy::cast_precision_loss)]
     fn variant_button_bounds(&self, mut bounds: Rectangle, nth: usize) -> Rectangle {
-        let num = self.state.buttons.len();
+        // Overflowing buttons keep their width, and are scrolled within the strip.
+        if let Some(width) = self.overflow.get() {
+            let strip = self.variant_strip_bounds(bounds);
+            let spacing = f32::from(self.spacing);
+
+            return Rectangle {
+                x: strip.x + nth as f32 * (width + spacing) - self.offset.get(),
+                width,
+                ..bounds
+            };
+        }
+
+        let num = self.count;
         if num != 0 {
             let spacing = f32::from(self.spacing);
             bounds.width = (bounds.width - (num as f32 * spacing) + spacing) / num as f32;
@@ -60,10 +78,11 @@ where
         let limits = limits.width(self.width);
         let text_size = renderer.default_size();
 
-        let (mut width, height) = self.max_button_dimensions(renderer, text_size, limits.max());
+        let (button_width, height) = self.max_button_dimensions(renderer, text_size, limits.max());
 
-        let num = self.state.buttons.len();
+        let num = self.count;
         let spacing = f32::from(self.spacing);
+        let mut width = button_width;
 
         if num != 0 {
             width = (num as f32 * width) + (num as f32 * spacing) - spacing;
@@ -73,6 +92,88 @@ where
             .height(Length::Units(height as u16))
             .resolve(Size::new(width, height));
 
+        // Buttons which do not fit are scrolled rather than squeezed.
+        self.overflow.set(if width > size.width {
+            Some(button_width)
+        } else {
+            None
+        });
+
         layout::Node::new(size)
     }
+
+    fn variant_strip_bounds(&self, bounds: Rectangle) -> Rectangle {
+        match self.variant_scroll_arrows(bounds) {
+            Some((previous, next)) => Rectangle {
+                x: previous.x + previous.width,
+                width: (next.x - previous.x - previous.width).max(0.0),
+                ..bounds
+            },
+            None => bounds,
+        }
+    }
+
+    fn variant_scroll_arrows(&self, bounds: Rectangle) -> Option<(Rectangle, Rectangle)> {
+        self.overflow.get()?;
+
+        let width = self.scroll_arrow_width().min(bounds.width / 2.0);
+        let previous = Rectangle { width, ..bounds };
+        let next = Rectangle {
+            x: bounds.x + bounds.width - width,
+            width,
+            ..bounds
+        };
+
+        Some((previous, next))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn variant_max_scroll(&self, bounds: Rectangle) -> f32 {
+        match self.overflow.get() {
+            Some(width) => {
+                let num = self.count as f32;
+                let spacing = f32::from(self.spacing);
+                let strip = self.variant_strip_bounds(bounds);
+                (num * width + (num - 1.0) * spacing - strip.width).max(0.0)
+            }
+            None => 0.0,
+        }
+    }
+
+    fn variant_scroll_to(&self, bounds: Rectangle, nth: usize) -> f32 {
+        let offset = self.offset.get();
+        let strip = self.variant_strip_bounds(bounds);
+        let button = self.variant_button_bounds(bounds, nth);
+
+        if button.x < strip.x {
+            offset - (strip.x - button.x)
+        } else if button.x + button.width > strip.x + strip.width {
+            offset + (button.x + button.width - strip.x - strip.width)
+        } else {
+            offset
+        }
+    }
+
+    fn variant_is_past_center(&self, button: Rectangle, cursor_position: Point) -> bool {
+        cursor_position.x > button.center_x()
+    }
+
+    fn variant_drop_indicator(&self, bounds: Rectangle, position: usize) -> Rectangle {
+        let x = match position.checked_sub(1) {
+            // Between two buttons, or after the last button.
+            Some(previous) => {
+                let previous = self.variant_button_bounds(bounds, previous);
+                previous.x + previous.width + f32::from(self.spacing) / 2.0
+            }
+            None => bounds.x,
+        };
+
+        Rectangle {
+            x: (x - DROP_INDICATOR_WIDTH / 2.0)
+                .min(bounds.x + bounds.width - DROP_INDICATOR_WIDTH)
+                .max(bounds.x),
+            width: DROP_INDICATOR_WIDTH,
+            ..bounds
+        }
+    }
 }
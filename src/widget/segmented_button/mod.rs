@@ -32,6 +32,15 @@
 //! application.state.activate(first_key);
 //! ```
 //!
+//! Choices may be disabled, so that they cannot be activated, or hidden without losing
+//! their key and data.
+//!
+//! ```ignore
+//! let key = application.state.insert("Choice D", 3);
+//! application.state.set_enabled(key, false);
+//! application.state.set_visible(key, false);
+//! ```
+//!
 //! Then use it in the view method to create segmented button widgets.
 //!
 //! ```ignore
@@ -40,6 +49,12 @@
 //!     .height(Length::Units(32))
 //!     .on_activate(AppMessage::Selected);
 //! ```
+//!
+//! The widget is focused by clicking it, or by tabbing into it with
+//! [`focus_next`](iced_native::widget::focus_next). The arrow keys, Home, and End then move
+//! the focus between buttons, and Enter or Space activates the focused button. Ctrl+Tab and
+//! Ctrl+Page Down activate the next button, while Ctrl+Shift+Tab and Ctrl+Page Up activate
+//! the previous button, as expected of view switchers.
 
 /// COSMIC configurations of [`SegmentedButton`].
 pub mod cosmic;
@@ -1,6 +1,7 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
+use crate::widget::{IconHandle, IconSource};
 use slotmap::{SecondaryMap, SlotMap};
 use std::borrow::Cow;
 
@@ -33,8 +34,54 @@ pub struct SharedWidgetState {
     /// The content used for drawing segmented buttons.
     pub buttons: SlotMap<Key, ButtonContent>,
 
+    /// The order that buttons are shown in.
+    ///
+    /// Slot maps reuse the slots of removed buttons, so their iteration order is not the
+    /// order that buttons were inserted in.
+    pub order: Vec<Key>,
+
     /// The actively-selected segmented button.
     pub active: Key,
+
+    /// Buttons which are shown, but cannot be activated.
+    pub disabled: SecondaryMap<Key, ()>,
+
+    /// Buttons which are not shown, while keeping their place and data.
+    pub hidden: SecondaryMap<Key, ()>,
+}
+
+impl SharedWidgetState {
+    /// The buttons which are shown, in the order that they are shown.
+    pub fn iter(&self) -> impl Iterator<Item = (Key, &ButtonContent)> {
+        self.order
+            .iter()
+            .filter(|&&key| self.is_visible(key))
+            .filter_map(|&key| self.buttons.get(key).map(|content| (key, content)))
+    }
+
+    /// The number of buttons which are shown.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Whether there are no buttons shown.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Whether a button may be activated.
+    #[must_use]
+    pub fn is_enabled(&self, key: Key) -> bool {
+        !self.disabled.contains_key(key)
+    }
+
+    /// Whether a button is shown.
+    #[must_use]
+    pub fn is_visible(&self, key: Key) -> bool {
+        !self.hidden.contains_key(key)
+    }
 }
 
 /// State which is most useful to the application.
@@ -62,13 +109,86 @@ impl<Data> State<Data> {
     /// Insert a new button.
     pub fn insert(&mut self, content: impl Into<ButtonContent>, data: Data) -> Key {
         let key = self.inner.buttons.insert(content.into());
+        self.inner.order.push(key);
         self.data.insert(key, data);
         key
     }
 
+    /// The position of a button among the buttons which are shown, or none if it is hidden.
+    #[must_use]
+    pub fn position(&self, key: Key) -> Option<usize> {
+        self.inner.iter().position(|(other, _)| other == key)
+    }
+
+    /// Moves a button to another position, such as when it was dragged there.
+    ///
+    /// The position is among the buttons which are shown, and hidden buttons keep their
+    /// place relative to the buttons around them.
+    pub fn reorder(&mut self, key: Key, position: usize) {
+        if let Some(current) = self.inner.order.iter().position(|&other| other == key) {
+            self.inner.order.remove(current);
+
+            let index = self
+                .inner
+                .order
+                .iter()
+                .enumerate()
+                .filter(|(_, &other)| self.inner.is_visible(other))
+                .nth(position)
+                .map_or(self.inner.order.len(), |(index, _)| index);
+
+            self.inner.order.insert(index, key);
+        }
+    }
+
+    /// Whether a button may be activated.
+    #[must_use]
+    pub fn is_enabled(&self, key: Key) -> bool {
+        self.inner.is_enabled(key)
+    }
+
+    /// Enables or disables a button.
+    ///
+    /// Disabled buttons are drawn greyed out, and are skipped by clicks and keyboard
+    /// navigation. A disabled button that is active remains active.
+    pub fn set_enabled(&mut self, key: Key, enabled: bool) {
+        if enabled {
+            self.inner.disabled.remove(key);
+        } else if self.inner.buttons.contains_key(key) {
+            self.inner.disabled.insert(key, ());
+        }
+    }
+
+    /// Whether a button is shown.
+    #[must_use]
+    pub fn is_visible(&self, key: Key) -> bool {
+        self.inner.is_visible(key)
+    }
+
+    /// Shows or hides a button, without removing it or its data.
+    ///
+    /// A hidden button that is active remains active, so the application may wish to
+    /// activate another button.
+    pub fn set_visible(&mut self, key: Key, visible: bool) {
+        if visible {
+            self.inner.hidden.remove(key);
+        } else if self.inner.buttons.contains_key(key) {
+            self.inner.hidden.insert(key, ());
+        }
+    }
+
+    /// Get the content of a button, such as to update its badge.
+    #[must_use]
+    pub fn content_mut(&mut self, key: Key) -> Option<&mut ButtonContent> {
+        self.inner.buttons.get_mut(key)
+    }
+
     /// Removes a button.
     pub fn remove(&mut self, key: Key) -> Option<Data> {
         self.inner.buttons.remove(key);
+        self.inner.order.retain(|&other| other != key);
+        self.inner.disabled.remove(key);
+        self.inner.hidden.remove(key);
         self.data.remove(key)
     }
 
@@ -81,26 +201,67 @@ impl<Data> State<Data> {
 /// Data to be drawn in a segmented button.
 pub struct ButtonContent {
     pub text: Cow<'static, str>,
+
+    /// An icon shown before the text, which is loaded when it is set.
+    pub icon: Option<IconHandle>,
+
+    /// A short label shown after the text, such as the number of unread items.
+    pub badge: Option<Cow<'static, str>>,
+
+    /// Shows only the icon, and not the text.
+    pub icon_only: bool,
 }
 
-impl From<String> for ButtonContent {
-    fn from(text: String) -> Self {
+impl ButtonContent {
+    /// Content with only text.
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'static, str>>) -> Self {
         ButtonContent {
-            text: Cow::Owned(text),
+            text: text.into(),
+            icon: None,
+            badge: None,
+            icon_only: false,
         }
     }
+
+    /// Shows an icon before the text.
+    ///
+    /// Named icons are looked up once, when the icon is set.
+    #[must_use]
+    pub fn icon<'a>(mut self, icon: impl Into<IconSource<'a>>) -> Self {
+        self.icon = IconHandle::from_source(icon.into(), super::widget::ICON_SIZE);
+        self
+    }
+
+    /// Shows a badge after the text, such as a count of unread items.
+    #[must_use]
+    pub fn badge(mut self, badge: impl ToString) -> Self {
+        self.badge = Some(Cow::Owned(badge.to_string()));
+        self
+    }
+
+    /// Shows only the icon, and not the text.
+    #[must_use]
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+}
+
+impl From<String> for ButtonContent {
+    fn from(text: String) -> Self {
+        ButtonContent::new(text)
+    }
 }
 
 impl From<&'static str> for ButtonContent {
     fn from(text: &'static str) -> Self {
-        ButtonContent {
-            text: Cow::Borrowed(text),
-        }
+        ButtonContent::new(text)
     }
 }
 
 impl From<Cow<'static, str>> for ButtonContent {
     fn from(text: Cow<'static, str>) -> Self {
-        ButtonContent { text }
+        ButtonContent::new(text)
     }
 }
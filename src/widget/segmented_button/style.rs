@@ -12,9 +12,16 @@ pub struct Appearance {
     pub border_end: Option<(f32, Color)>,
     pub border_start: Option<(f32, Color)>,
     pub border_top: Option<(f32, Color)>,
+    /// The background of the badges of buttons, or none to draw badges as plain text.
+    pub badge_background: Option<Background>,
+    pub badge_text_color: Color,
+    /// The width and color of the outline drawn around the button with keyboard focus.
+    pub focus_outline: (f32, Color),
     pub active: ButtonStatusAppearance,
     pub inactive: ButtonStatusAppearance,
     pub hover: ButtonStatusAppearance,
+    /// The appearance of buttons which cannot be activated.
+    pub disabled: ButtonStatusAppearance,
 }
 
 /// The appearance of a button in the segmented button
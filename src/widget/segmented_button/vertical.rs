@@ -1,8 +1,8 @@
 use super::state::State;
 use super::style::StyleSheet;
-use super::widget::{SegmentedButton, SegmentedVariant};
+use super::widget::{SegmentedButton, SegmentedVariant, DROP_INDICATOR_WIDTH};
 
-use iced::{Length, Rectangle, Size};
+use iced::{Length, Point, Rectangle, Size};
 use iced_native::layout;
 
 /// A type marker defining the vertical variant of a [`SegmentedButton`].
@@ -18,7 +18,10 @@ pub fn vertical_segmented_button<Message, Renderer, Data>(
     state: &State<Data>,
 ) -> SegmentedButton<Vertical, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
 {
     SegmentedButton::new(&state.inner)
@@ -26,7 +29,10 @@ where
 
 impl<'a, Message, Renderer> SegmentedVariant for SegmentedButton<'a, Vertical, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
 {
     type Renderer = Renderer;
@@ -40,7 +46,7 @@ where
 
     #[allow(clippy::cast_precision_loss)]
     fn variant_button_bounds(&self, mut bounds: Rectangle, nth: usize) -> Rectangle {
-        let num = self.state.buttons.len();
+        let num = self.count;
         if num != 0 {
             let spacing = f32::from(self.spacing);
             bounds.height = (bounds.height - (num as f32 * spacing) + spacing) / num as f32;
@@ -62,7 +68,7 @@ where
 
         let (width, mut height) = self.max_button_dimensions(renderer, text_size, limits.max());
 
-        let num = self.state.buttons.len();
+        let num = self.count;
         let spacing = f32::from(self.spacing);
 
         if num != 0 {
@@ -75,4 +81,27 @@ where
 
         layout::Node::new(size)
     }
+
+    fn variant_is_past_center(&self, button: Rectangle, cursor_position: Point) -> bool {
+        cursor_position.y > button.center_y()
+    }
+
+    fn variant_drop_indicator(&self, bounds: Rectangle, position: usize) -> Rectangle {
+        let y = match position.checked_sub(1) {
+            // Between two buttons, or after the last button.
+            Some(previous) => {
+                let previous = self.variant_button_bounds(bounds, previous);
+                previous.y + previous.height + f32::from(self.spacing) / 2.0
+            }
+            None => bounds.y,
+        };
+
+        Rectangle {
+            y: (y - DROP_INDICATOR_WIDTH / 2.0)
+                .min(bounds.y + bounds.height - DROP_INDICATOR_WIDTH)
+                .max(bounds.y),
+            height: DROP_INDICATOR_WIDTH,
+            ..bounds
+        }
+    }
 }
@@ -1,7 +1,10 @@
+use std::cell::Cell;
 use std::marker::PhantomData;
 
-use super::state::{Key, SharedWidgetState};
+use super::state::{ButtonContent, Key, SharedWidgetState};
 use super::style::StyleSheet;
+use crate::theme::{Density, Tokens};
+use crate::widget::IconHandle;
 
 use derive_setters::Setters;
 use iced::{
@@ -9,8 +12,20 @@ use iced::{
     Size,
 };
 use iced_core::BorderRadius;
-use iced_native::widget::tree;
-use iced_native::{layout, renderer, widget::Tree, Clipboard, Layout, Shell, Widget};
+use iced_native::widget::{operation, tree, Id, Operation};
+use iced_native::{keyboard, layout, renderer, widget::Tree, Clipboard, Layout, Shell, Widget};
+
+/// Size of the icon shown in a button.
+pub(super) const ICON_SIZE: u16 = 16;
+
+/// Distance that the cursor must move while pressed before a button is dragged.
+const DRAG_THRESHOLD: f32 = 8.0;
+
+/// Thickness of the line which indicates where a dragged button will be dropped.
+pub(super) const DROP_INDICATOR_WIDTH: f32 = 2.0;
+
+/// Distance that a line of mouse wheel scrolling moves overflowing buttons.
+const SCROLL_LINE: f32 = 60.0;
 
 /// Isolates variant-specific behaviors from [`SegmentedButton`].
 pub trait SegmentedVariant {
@@ -29,17 +44,52 @@ pub trait SegmentedVariant {
 
     /// Calculates the layout of this variant.
     fn variant_layout(&self, renderer: &Self::Renderer, limits: &layout::Limits) -> layout::Node;
+
+    /// Whether the cursor is past the center of a button, in the direction of the layout.
+    fn variant_is_past_center(&self, button: Rectangle, cursor_position: Point) -> bool;
+
+    /// Calculates the bounds of the line drawn before the button at `position` while dragging.
+    fn variant_drop_indicator(&self, bounds: Rectangle, position: usize) -> Rectangle;
+
+    /// The bounds which the buttons are shown within, excluding any scroll arrows.
+    fn variant_strip_bounds(&self, bounds: Rectangle) -> Rectangle {
+        bounds
+    }
+
+    /// The bounds of the arrows which scroll to the previous and next buttons, if they overflow.
+    fn variant_scroll_arrows(&self, _bounds: Rectangle) -> Option<(Rectangle, Rectangle)> {
+        None
+    }
+
+    /// How far the buttons may be scrolled, which is zero unless they overflow.
+    fn variant_max_scroll(&self, _bounds: Rectangle) -> f32 {
+        0.0
+    }
+
+    /// The scroll offset at which the whole of the button at `position` is shown.
+    fn variant_scroll_to(&self, _bounds: Rectangle, _position: usize) -> f32 {
+        0.0
+    }
 }
 
 #[derive(Setters)]
 pub struct SegmentedButton<'a, Variant, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
 {
     /// Contains application state also used for drawing.
     #[setters(skip)]
     pub(super) state: &'a SharedWidgetState,
+    /// The number of buttons which are shown, counted once since the state is borrowed.
+    #[setters(skip)]
+    pub(super) count: usize,
+    /// The ID used to focus the widget with an operation.
+    #[setters(strip_option)]
+    pub(super) id: Option<Id>,
     /// Desired font for active tabs.
     pub(super) font_active: Renderer::Font,
     /// Desired font for hovered tabs.
@@ -51,11 +101,23 @@ where
     /// Desired height of the widget.
     pub(super) height: Length,
     /// Padding around a button.
+    #[setters(skip)]
     pub(super) button_padding: [u16; 4],
     /// Desired height of a button.
+    #[setters(skip)]
     pub(super) button_height: u16,
+    #[setters(skip)]
+    /// The density of the theme, which spaces the contents of the buttons.
+    pub(super) density: Density,
+    #[setters(skip)]
+    /// Derives the padding and height of a button from the tokens of the density, unless
+    /// they were set explicitly.
+    pub(super) button_size: Option<fn(&Tokens) -> ([u16; 4], u16)>,
     /// Desired spacing between buttons.
     pub(super) spacing: u16,
+    /// Whether Ctrl+Tab, Ctrl+Page Down, and Ctrl+Page Up switch buttons while the widget
+    /// is not focused, such as for view switchers whose pages hold the focus.
+    pub(super) global_shortcuts: bool,
     /// Style to draw the widget in.
     #[setters(into)]
     pub(super) style: <Renderer::Theme as StyleSheet>::Style,
@@ -63,13 +125,38 @@ where
     /// Emits the ID of the activated widget on selection.
     pub(super) on_activate: Option<Box<dyn Fn(Key) -> Message>>,
     #[setters(skip)]
+    /// Emits the ID of a button when its close button is clicked.
+    pub(super) on_close: Option<Box<dyn Fn(Key) -> Message>>,
+    #[setters(skip)]
+    /// The icon of the close buttons, which is loaded once for every button.
+    close_icon: IconHandle,
+    #[setters(skip)]
+    /// The icons of the arrows which scroll to the previous and next buttons.
+    scroll_icons: (IconHandle, IconHandle),
+    #[setters(skip)]
+    /// Emits the ID of a dragged button and the position it was dropped at.
+    pub(super) on_reorder: Option<Box<dyn Fn(Key, usize) -> Message>>,
+    #[setters(skip)]
+    /// The width of each button while they overflow the widget, which is set by the layout.
+    pub(super) overflow: Cell<Option<f32>>,
+    #[setters(skip)]
+    /// How far overflowing buttons are scrolled, which is copied from the tree, and kept
+    /// within range by the layout.
+    pub(super) offset: Cell<f32>,
+    #[setters(skip)]
+    /// The active button when the buttons were last scrolled to show it.
+    revealed: Cell<Key>,
+    #[setters(skip)]
     /// Defines the implementation of this struct
     variant: PhantomData<Variant>,
 }
 
 impl<'a, Variant, Message, Renderer> SegmentedButton<'a, Variant, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
     Self: SegmentedVariant<Renderer = Renderer>,
 {
@@ -77,6 +164,8 @@ where
     pub fn new(state: &'a SharedWidgetState) -> Self {
         Self {
             state,
+            count: state.len(),
+            id: None,
             font_active: Renderer::Font::default(),
             font_hovered: Renderer::Font::default(),
             font_inactive: Renderer::Font::default(),
@@ -84,13 +173,61 @@ where
             width: Length::Fill,
             button_padding: [4, 4, 4, 4],
             button_height: 32,
+            density: Density::default(),
+            button_size: None,
             spacing: 0,
+            global_shortcuts: false,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
             on_activate: None,
+            on_close: None,
+            close_icon: IconHandle::named("window-close-symbolic", ICON_SIZE),
+            scroll_icons: (
+                IconHandle::named("go-previous-symbolic", ICON_SIZE),
+                IconHandle::named("go-next-symbolic", ICON_SIZE),
+            ),
+            on_reorder: None,
+            overflow: Cell::new(None),
+            offset: Cell::new(0.0),
+            revealed: Cell::new(Key::default()),
             variant: PhantomData,
         }
     }
 
+    /// Padding around a button.
+    #[must_use]
+    pub fn button_padding(mut self, padding: [u16; 4]) -> Self {
+        self.button_padding = padding;
+        self.button_size = None;
+        self
+    }
+
+    /// Desired height of a button.
+    #[must_use]
+    pub fn button_height(mut self, height: u16) -> Self {
+        self.button_height = height;
+        self.button_size = None;
+        self
+    }
+
+    /// Sizes the buttons from the tokens of each density, until a size is set explicitly.
+    #[must_use]
+    pub(super) fn button_size(mut self, size: fn(&Tokens) -> ([u16; 4], u16)) -> Self {
+        self.button_size = Some(size);
+        let density = self.density;
+        self.density(density)
+    }
+
+    /// Sets the density of the theme, which spaces the contents of the buttons, and sizes
+    /// the buttons of the COSMIC styles.
+    #[must_use]
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        if let Some(size) = self.button_size {
+            (self.button_padding, self.button_height) = size(&density.tokens());
+        }
+        self
+    }
+
     /// Emits the ID of the activated widget on selection.
     #[must_use]
     pub fn on_activate(mut self, on_activate: impl Fn(Key) -> Message + 'static) -> Self {
@@ -98,14 +235,210 @@ where
         self
     }
 
+    /// Shows a close button on each button, emitting its ID when clicked.
+    ///
+    /// Buttons may also be closed by clicking them with the middle mouse button. The
+    /// application should remove the button from its state.
+    #[must_use]
+    pub fn on_close(mut self, on_close: impl Fn(Key) -> Message + 'static) -> Self {
+        self.on_close = Some(Box::from(on_close));
+        self
+    }
+
+    /// Makes the buttons draggable, emitting the ID of a dropped button and its new position.
+    ///
+    /// The application should call [`State::reorder`](super::State::reorder) with them.
+    #[must_use]
+    pub fn on_reorder(mut self, on_reorder: impl Fn(Key, usize) -> Message + 'static) -> Self {
+        self.on_reorder = Some(Box::from(on_reorder));
+        self
+    }
+
+    /// Width reserved at the end of each button for its close button.
+    fn close_space(&self) -> f32 {
+        if self.on_close.is_some() {
+            f32::from(ICON_SIZE) + f32::from(self.density.tokens().spacing.xs)
+        } else {
+            0.0
+        }
+    }
+
+    /// The bounds of the close button at the end of a button.
+    fn close_bounds(&self, button: Rectangle) -> Rectangle {
+        let size = f32::from(ICON_SIZE);
+        let padding = f32::from(self.button_padding[2]).max(f32::from(self.button_padding[0]));
+
+        Rectangle {
+            x: button.x + button.width - padding - size,
+            y: button.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+
+    /// Scrolls overflowing buttons to `offset`, within the range that they may be scrolled.
+    fn scroll_to(&self, state: &mut UniqueWidgetState, bounds: Rectangle, offset: f32) {
+        let offset = offset.min(self.variant_max_scroll(bounds)).max(0.0);
+        state.offset = offset;
+        self.offset.set(offset);
+    }
+
+    /// Handles a key press while the widget is focused.
+    ///
+    /// The arrow keys, Home, and End move the focus between enabled buttons, which Enter and
+    /// Space activate. Ctrl+Tab and Ctrl+Page Down activate the next button, and Ctrl+Shift+Tab
+    /// and Ctrl+Page Up the previous, wrapping around at either end. With
+    /// [`global_shortcuts`](Self::global_shortcuts), those are also handled while unfocused.
+    fn navigate(
+        &self,
+        state: &mut UniqueWidgetState,
+        bounds: Rectangle,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // Disabled buttons are skipped, and cannot be focused or activated.
+        let enabled: Vec<usize> = self
+            .state
+            .iter()
+            .enumerate()
+            .filter(|(_, (key, _))| self.state.is_enabled(*key))
+            .map(|(nth, _)| nth)
+            .collect();
+
+        let (first, last) = match (enabled.first(), enabled.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return event::Status::Ignored,
+        };
+
+        let previous = |from: Option<usize>| {
+            from.and_then(|from| enabled.iter().rev().copied().find(|&nth| nth < from))
+        };
+        let next = |from: Option<usize>| {
+            from.and_then(|from| enabled.iter().copied().find(|&nth| nth > from))
+        };
+
+        let position_of = |key| self.state.iter().position(|(other, _)| other == key);
+        let active = position_of(self.state.active);
+        let current = state.focused.and_then(position_of).or(active);
+
+        if modifiers.control() {
+            let is_backwards = match key_code {
+                keyboard::KeyCode::Tab => modifiers.shift(),
+                keyboard::KeyCode::PageUp => true,
+                keyboard::KeyCode::PageDown => false,
+                _ => return event::Status::Ignored,
+            };
+
+            let nth = if is_backwards {
+                previous(active).unwrap_or(last)
+            } else {
+                next(active).unwrap_or(first)
+            };
+
+            self.focus(state, bounds, nth);
+            if let (Some(key), Some(on_activate)) = (state.focused, self.on_activate.as_ref()) {
+                shell.publish(on_activate(key));
+            }
+
+            return event::Status::Captured;
+        }
+
+        let nth = match key_code {
+            keyboard::KeyCode::Left | keyboard::KeyCode::Up => previous(current)
+                .or_else(|| current.map(|_| first))
+                .unwrap_or(last),
+            keyboard::KeyCode::Right | keyboard::KeyCode::Down => next(current)
+                .or_else(|| current.map(|_| last))
+                .unwrap_or(first),
+            keyboard::KeyCode::Home => first,
+            keyboard::KeyCode::End => last,
+            keyboard::KeyCode::Enter
+            | keyboard::KeyCode::NumpadEnter
+            | keyboard::KeyCode::Space => {
+                let key = current
+                    .and_then(|nth| self.state.iter().nth(nth))
+                    .map(|(key, _)| key)
+                    .filter(|&key| self.state.is_enabled(key));
+                if let (Some(key), Some(on_activate)) = (key, self.on_activate.as_ref()) {
+                    shell.publish(on_activate(key));
+                    return event::Status::Captured;
+                }
+
+                return event::Status::Ignored;
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        self.focus(state, bounds, nth);
+        event::Status::Captured
+    }
+
+    /// Moves the keyboard focus to the button at `nth`, scrolling it into view.
+    fn focus(&self, state: &mut UniqueWidgetState, bounds: Rectangle, nth: usize) {
+        if let Some((key, _)) = self.state.iter().nth(nth) {
+            state.focused = Some(key);
+            self.scroll_to(state, bounds, self.variant_scroll_to(bounds, nth));
+        }
+    }
+
+    /// The position between buttons where a button dragged to the cursor would be dropped.
+    fn drop_position(&self, bounds: Rectangle, cursor_position: Point) -> usize {
+        (0..self.count)
+            .filter(|&nth| {
+                self.variant_is_past_center(
+                    self.variant_button_bounds(bounds, nth),
+                    cursor_position,
+                )
+            })
+            .count()
+    }
+
+    /// Measures the icon, text, and badge of a button, excluding its padding.
+    fn measure_content(
+        &self,
+        renderer: &Renderer,
+        content: &ButtonContent,
+        text_size: u16,
+        bounds: Size,
+    ) -> ContentSize {
+        let spacing = self.density.tokens().spacing;
+
+        let text = (!content.icon_only || content.icon.is_none()).then(|| {
+            let (w, h) = renderer.measure(&content.text, text_size, Default::default(), bounds);
+            Size::new(w, h)
+        });
+
+        let badge = content.badge.as_deref().map(|badge| {
+            let (w, h) = renderer.measure(badge, badge_text_size(), Default::default(), bounds);
+            let height = h + 2.0 * f32::from(spacing.xxs);
+
+            // Badges are at least circular, so that a single digit is not squeezed.
+            Size::new((w + 2.0 * f32::from(spacing.xs)).max(height), height)
+        });
+
+        ContentSize {
+            icon: content.icon.is_some().then(|| f32::from(ICON_SIZE)),
+            text,
+            badge,
+            spacing: f32::from(spacing.xs),
+        }
+    }
+
+    /// Width of the arrows which scroll overflowing buttons.
+    pub(super) fn scroll_arrow_width(&self) -> f32 {
+        f32::from(ICON_SIZE) + 2.0 * f32::from(self.density.tokens().spacing.xs)
+    }
+
     pub(super) fn measure_button(
         &self,
         renderer: &Renderer,
-        text: &str,
+        content: &ButtonContent,
         text_size: u16,
         bounds: Size,
     ) -> (f32, f32) {
-        let (mut w, mut h) = renderer.measure(text, text_size, Default::default(), bounds);
+        let size = self.measure_content(renderer, content, text_size, bounds);
+        let (mut w, mut h) = (size.width() + self.close_space(), size.height());
         w += f32::from(self.button_padding[0]) + f32::from(self.button_padding[2]);
         h += f32::from(self.button_padding[1]) + f32::from(self.button_padding[3]);
         h = h.max(f32::from(self.button_height));
@@ -121,8 +454,8 @@ where
         let mut width = 0.0f32;
         let mut height = 0.0f32;
 
-        for (_, content) in self.state.buttons.iter() {
-            let (w, h) = self.measure_button(renderer, &content.text, text_size, bounds);
+        for (_, content) in self.state.iter() {
+            let (w, h) = self.measure_button(renderer, content, text_size, bounds);
             height = height.max(h);
             width = width.max(w);
         }
@@ -134,7 +467,10 @@ where
 impl<'a, Variant, Message, Renderer> Widget<Message, Renderer>
     for SegmentedButton<'a, Variant, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>,
     Renderer::Theme: StyleSheet,
     Self: SegmentedVariant<Renderer = Renderer>,
     Message: 'static + Clone,
@@ -147,6 +483,12 @@ where
         tree::State::new(UniqueWidgetState::default())
     }
 
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_ref::<UniqueWidgetState>();
+        self.offset.set(state.offset);
+        self.revealed.set(state.revealed);
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -156,7 +498,38 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        self.variant_layout(renderer, limits)
+        let node = self.variant_layout(renderer, limits);
+        let bounds = Rectangle::new(Point::ORIGIN, node.size());
+
+        // Keep the active button visible when it changes, and within range after resizes.
+        if self.revealed.replace(self.state.active) != self.state.active {
+            if let Some(active) = self
+                .state
+                .iter()
+                .position(|(key, _)| key == self.state.active)
+            {
+                self.offset.set(self.variant_scroll_to(bounds, active));
+            }
+        }
+
+        self.offset.set(
+            self.offset
+                .get()
+                .min(self.variant_max_scroll(bounds))
+                .max(0.0),
+        );
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<UniqueWidgetState>();
+        operation.focusable(state, self.id.as_ref());
     }
 
     fn on_event(
@@ -172,20 +545,183 @@ where
         let bounds = layout.bounds();
         let state = tree.state.downcast_mut::<UniqueWidgetState>();
 
-        if bounds.contains(cursor_position) {
-            for (nth, (key, _)) in self.state.buttons.iter().enumerate() {
+        // Keep the scrolling of the last layout.
+        state.offset = self.offset.get();
+        state.revealed = self.revealed.get();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = bounds.contains(cursor_position);
+                state.is_focus_visible = false;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused || (self.global_shortcuts && modifiers.control()) => {
+                let status = self.navigate(state, bounds, key_code, modifiers, shell);
+                if status == event::Status::Captured && state.is_focused {
+                    state.is_focus_visible = true;
+                }
+
+                return status;
+            }
+
+            _ => (),
+        }
+
+        if let Some((previous, next)) = self.variant_scroll_arrows(bounds) {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    let step = self.variant_strip_bounds(bounds).width / 2.0;
+
+                    if previous.contains(cursor_position) {
+                        self.scroll_to(state, bounds, state.offset - step);
+                        return event::Status::Captured;
+                    }
+
+                    if next.contains(cursor_position) {
+                        self.scroll_to(state, bounds, state.offset + step);
+                        return event::Status::Captured;
+                    }
+                }
+
+                Event::Mouse(mouse::Event::WheelScrolled { delta })
+                    if bounds.contains(cursor_position) =>
+                {
+                    let (x, y) = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => (x * SCROLL_LINE, y * SCROLL_LINE),
+                        mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                    };
+
+                    // Either wheel scrolls, since most mice only have a vertical wheel.
+                    let delta = if x.abs() > y.abs() { x } else { y };
+                    self.scroll_to(state, bounds, state.offset - delta);
+                    return event::Status::Captured;
+                }
+
+                _ => (),
+            }
+        }
+
+        // Releasing the cursor ends the press wherever it is, and only activates or closes
+        // the button that the press began on.
+        let released = match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => Some((
+                state.pressed.take().map(|(key, _)| key),
+                state.close_pressed.take(),
+            )),
+            _ => None,
+        };
+
+        match event {
+            // Begin dragging the pressed button once the cursor moves far enough.
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some((_, origin)), Some(_)) = (state.pressed, self.on_reorder.as_ref()) {
+                    let distance =
+                        (cursor_position.x - origin.x).hypot(cursor_position.y - origin.y);
+                    state.dragging = state.dragging || distance > DRAG_THRESHOLD;
+
+                    if state.dragging {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if std::mem::take(&mut state.dragging) {
+                    let from = released.and_then(|(pressed, _)| pressed).and_then(|key| {
+                        self.state
+                            .iter()
+                            .position(|(other, _)| other == key)
+                            .map(|from| (key, from))
+                    });
+
+                    if let (Some((key, from)), Some(on_reorder)) = (from, self.on_reorder.as_ref())
+                    {
+                        // Removing the button shifts every button after it back by one.
+                        let position = self.drop_position(bounds, cursor_position);
+                        let to = if position > from {
+                            position - 1
+                        } else {
+                            position
+                        };
+
+                        if to != from {
+                            shell.publish(on_reorder(key, to));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => (),
+        }
+
+        // Buttons which are scrolled out of the strip cannot be clicked.
+        if self.variant_strip_bounds(bounds).contains(cursor_position) {
+            for (nth, (key, _)) in self.state.iter().enumerate() {
                 let bounds = self.variant_button_bounds(bounds, nth);
                 if bounds.contains(cursor_position) {
+                    // Disabled buttons are neither highlighted nor clickable.
+                    if !self.state.is_enabled(key) {
+                        state.hovered = Key::default();
+                        break;
+                    }
+
                     // Record that the mouse is hovering over this button.
                     state.hovered = key;
 
-                    if let Some(on_activate) = self.on_activate.as_ref() {
-                        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                        | Event::Touch(touch::Event::FingerLifted { .. }) = event
-                        {
-                            shell.publish(on_activate(key));
-                            return event::Status::Captured;
+                    match event {
+                        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                            // A press on the close button neither activates nor drags.
+                            if self.on_close.is_some()
+                                && self.close_bounds(bounds).contains(cursor_position)
+                            {
+                                state.close_pressed = Some(key);
+                            } else {
+                                state.pressed = Some((key, cursor_position));
+                            }
+
+                            state.focused = Some(key);
+                        }
+
+                        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                            let (pressed, close_pressed) = released.unwrap_or_default();
+
+                            if let Some(on_close) = self.on_close.as_ref() {
+                                if close_pressed == Some(key)
+                                    && self.close_bounds(bounds).contains(cursor_position)
+                                {
+                                    shell.publish(on_close(key));
+                                    return event::Status::Captured;
+                                }
+                            }
+
+                            if let Some(on_activate) = self.on_activate.as_ref() {
+                                if pressed == Some(key) {
+                                    shell.publish(on_activate(key));
+                                    return event::Status::Captured;
+                                }
+                            }
                         }
+
+                        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)) => {
+                            if let Some(on_close) = self.on_close.as_ref() {
+                                shell.publish(on_close(key));
+                                return event::Status::Captured;
+                            }
+                        }
+
+                        _ => (),
                     }
                 }
             }
@@ -198,17 +734,32 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor_position: iced::Point,
         _viewport: &iced::Rectangle,
         _renderer: &Renderer,
     ) -> iced_native::mouse::Interaction {
+        if tree.state.downcast_ref::<UniqueWidgetState>().dragging {
+            return iced_native::mouse::Interaction::Grabbing;
+        }
+
         let bounds = layout.bounds();
-        if (0..self.state.buttons.len()).any(|nth| {
-            self.variant_button_bounds(bounds, nth)
-                .contains(cursor_position)
-        }) {
+        let is_over_arrow = self
+            .variant_scroll_arrows(bounds)
+            .map_or(false, |(previous, next)| {
+                previous.contains(cursor_position) || next.contains(cursor_position)
+            });
+
+        let is_over_button = self.variant_strip_bounds(bounds).contains(cursor_position)
+            && self.state.iter().enumerate().any(|(nth, (key, _))| {
+                self.state.is_enabled(key)
+                    && self
+                        .variant_button_bounds(bounds, nth)
+                        .contains(cursor_position)
+            });
+
+        if is_over_arrow || is_over_button {
             iced_native::mouse::Interaction::Pointer
         } else {
             iced_native::mouse::Interaction::Idle
@@ -222,13 +773,13 @@ where
         theme: &<Renderer as iced_native::Renderer>::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: iced::Point,
+        cursor_position: iced::Point,
         _viewport: &iced::Rectangle,
     ) {
         let state = tree.state.downcast_ref::<UniqueWidgetState>();
         let appearance = Self::variant_appearance(theme, &self.style);
         let bounds = layout.bounds();
-        let button_amount = self.state.buttons.len();
+        let button_amount = self.count;
 
         // Draw the background, if a background was defined.
         if let Some(background) = appearance.background {
@@ -243,71 +794,287 @@ where
             );
         }
 
-        // Draw each of the buttons in the widget.
-        for (nth, (key, content)) in self.state.buttons.iter().enumerate() {
-            let bounds = self.variant_button_bounds(bounds, nth);
-
-            let (status_appearance, font) = if self.state.active == key {
-                (appearance.active, &self.font_active)
-            } else if state.hovered == key {
-                (appearance.hover, &self.font_hovered)
-            } else {
-                (appearance.inactive, &self.font_inactive)
-            };
+        if let Some((previous, next)) = self.variant_scroll_arrows(bounds) {
+            let max_scroll = self.variant_max_scroll(bounds);
+            let offset = self.offset.get();
 
-            let x = bounds.center_x();
-            let y = bounds.center_y();
+            for (arrow, icon, is_enabled) in [
+                (previous, &self.scroll_icons.0, offset > 0.0),
+                (next, &self.scroll_icons.1, offset < max_scroll),
+            ] {
+                let size = f32::from(ICON_SIZE);
+                let color = appearance.inactive.text_color;
 
-            let button_appearance = if nth == 0 {
-                status_appearance.first
-            } else if nth + 1 == button_amount {
-                status_appearance.last
-            } else {
-                status_appearance.middle
-            };
-
-            // Render the background of the button.
-            if status_appearance.background.is_some() {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds,
-                        border_radius: button_appearance.border_radius,
-                        border_width: 0.0,
-                        border_color: Color::TRANSPARENT,
+                icon.draw(
+                    renderer,
+                    Rectangle {
+                        x: arrow.center_x() - size / 2.0,
+                        y: arrow.center_y() - size / 2.0,
+                        width: size,
+                        height: size,
+                    },
+                    if is_enabled {
+                        color
+                    } else {
+                        Color { a: 0.3, ..color }
                     },
-                    status_appearance
-                        .background
-                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
                 );
             }
+        }
+
+        // The button outlined after the keyboard has focused or navigated the widget.
+        let focused = if state.is_focused && state.is_focus_visible {
+            Some(state.focused.unwrap_or(self.state.active))
+        } else {
+            None
+        };
+
+        // Draw each of the buttons in the widget, clipped to the strip while they overflow.
+        renderer.with_layer(self.variant_strip_bounds(bounds), |renderer| {
+            for (nth, (key, content)) in self.state.iter().enumerate() {
+                let bounds = self.variant_button_bounds(bounds, nth);
+
+                let is_enabled = self.state.is_enabled(key);
+                let (status_appearance, font) = if !is_enabled {
+                    (appearance.disabled, &self.font_inactive)
+                } else if self.state.active == key {
+                    (appearance.active, &self.font_active)
+                } else if state.hovered == key {
+                    (appearance.hover, &self.font_hovered)
+                } else {
+                    (appearance.inactive, &self.font_inactive)
+                };
+
+                // Content is centered in the space before the close button.
+                let x = bounds.center_x() - self.close_space() / 2.0;
+                let y = bounds.center_y();
+
+                let button_appearance = if nth == 0 {
+                    status_appearance.first
+                } else if nth + 1 == button_amount {
+                    status_appearance.last
+                } else {
+                    status_appearance.middle
+                };
+
+                // Render the background of the button.
+                if status_appearance.background.is_some() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: button_appearance.border_radius,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        status_appearance
+                            .background
+                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                    );
+                }
+
+                // Draw the bottom border defined for this button.
+                if let Some((width, background)) = button_appearance.border_bottom {
+                    let mut bounds = bounds;
+                    bounds.y = bounds.y + bounds.height - width;
+                    bounds.height = width;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: BorderRadius::from(0.0),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        background,
+                    );
+                }
+
+                // Draw the top border defined for this button.
+                if let Some((width, background)) = button_appearance.border_top {
+                    let mut bounds = bounds;
+                    bounds.height = width;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: BorderRadius::from(0.0),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        background,
+                    );
+                }
+
+                // Draw the start border defined for this button.
+                if let Some((width, background)) = button_appearance.border_start {
+                    let mut bounds = bounds;
+                    bounds.width = width;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: BorderRadius::from(0.0),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        background,
+                    );
+                }
+
+                // Draw the end border defined for this button.
+                if let Some((width, background)) = button_appearance.border_end {
+                    let mut bounds = bounds;
+                    bounds.x = bounds.x + bounds.width - width;
+                    bounds.width = width;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: BorderRadius::from(0.0),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        background,
+                    );
+                }
+
+                // Center the icon, text, and badge together within the button.
+                let text_size = renderer.default_size();
+                let size = self.measure_content(renderer, content, text_size, bounds.size());
+                let mut x = x - size.width() / 2.0;
+
+                if let (Some(icon), Some(width)) = (content.icon.as_ref(), size.icon) {
+                    icon.draw(
+                        renderer,
+                        Rectangle {
+                            x,
+                            y: y - width / 2.0,
+                            width,
+                            height: width,
+                        },
+                        status_appearance.text_color,
+                    );
+
+                    x += width + size.spacing;
+                }
+
+                if let Some(text) = size.text {
+                    renderer.fill_text(iced_native::text::Text {
+                        content: &content.text,
+                        size: f32::from(text_size),
+                        bounds: Rectangle { x, y, ..bounds },
+                        color: status_appearance.text_color,
+                        font: font.clone(),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+
+                    x += text.width + size.spacing;
+                }
+
+                if let (Some(badge), Some(badge_size)) = (content.badge.as_deref(), size.badge) {
+                    let badge_bounds = Rectangle {
+                        x,
+                        y: y - badge_size.height / 2.0,
+                        width: badge_size.width,
+                        height: badge_size.height,
+                    };
+
+                    let color = match appearance.badge_background {
+                        Some(background) => {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: badge_bounds,
+                                    border_radius: BorderRadius::from(badge_size.height / 2.0),
+                                    border_width: 0.0,
+                                    border_color: Color::TRANSPARENT,
+                                },
+                                background,
+                            );
+
+                            appearance.badge_text_color
+                        }
+                        None => status_appearance.text_color,
+                    };
+
+                    renderer.fill_text(iced_native::text::Text {
+                        content: badge,
+                        size: f32::from(badge_text_size()),
+                        bounds: Rectangle {
+                            x: badge_bounds.center_x(),
+                            y,
+                            ..badge_bounds
+                        },
+                        color,
+                        font: Renderer::Font::default(),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+
+                if self.on_close.is_some() {
+                    let close = self.close_bounds(bounds);
+
+                    // Highlight the close button while the cursor is over it.
+                    if is_enabled && close.contains(cursor_position) {
+                        let padding = f32::from(self.density.tokens().spacing.xxs);
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: close.x - padding,
+                                    y: close.y - padding,
+                                    width: close.width + 2.0 * padding,
+                                    height: close.height + 2.0 * padding,
+                                },
+                                border_radius: BorderRadius::from(close.width / 2.0 + padding),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            Background::Color(Color {
+                                a: 0.1,
+                                ..status_appearance.text_color
+                            }),
+                        );
+                    }
+
+                    self.close_icon
+                        .draw(renderer, close, status_appearance.text_color);
+                }
+
+                if focused == Some(key) {
+                    let (width, color) = appearance.focus_outline;
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: button_appearance.border_radius,
+                            border_width: width,
+                            border_color: color,
+                        },
+                        Background::Color(Color::TRANSPARENT),
+                    );
+                }
+            }
+        });
 
-            // Draw the bottom border defined for this button.
-            if let Some((width, background)) = button_appearance.border_bottom {
-                let mut bounds = bounds;
-                bounds.y = bounds.y + bounds.height - width;
-                bounds.height = width;
+        // Draw a line between the buttons where the dragged button will be dropped.
+        if state.dragging {
+            let from = state
+                .pressed
+                .and_then(|(pressed, _)| self.state.iter().position(|(key, _)| key == pressed));
+            let position = self.drop_position(bounds, cursor_position);
 
+            // Dropping a button beside itself would not move it, so nothing is indicated there.
+            if from.map_or(false, |from| position != from && position != from + 1) {
                 renderer.fill_quad(
                     renderer::Quad {
-                        bounds,
-                        border_radius: BorderRadius::from(0.0),
+                        bounds: self.variant_drop_indicator(bounds, position),
+                        border_radius: BorderRadius::from(DROP_INDICATOR_WIDTH / 2.0),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
                     },
-                    background,
+                    Background::Color(appearance.active.text_color),
                 );
             }
-
-            // Draw the text in this button.
-            renderer.fill_text(iced_native::text::Text {
-                content: &content.text,
-                size: f32::from(renderer.default_size()),
-                bounds: Rectangle { x, y, ..bounds },
-                color: status_appearance.text_color,
-                font: font.clone(),
-                horizontal_alignment: alignment::Horizontal::Center,
-                vertical_alignment: alignment::Vertical::Center,
-            });
         }
     }
 
@@ -324,14 +1091,18 @@ where
 impl<'a, Variant, Message, Renderer> From<SegmentedButton<'a, Variant, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer + 'a,
+    Renderer: iced_native::Renderer
+        + iced_native::text::Renderer
+        + iced_native::svg::Renderer
+        + iced_native::image::Renderer<Handle = iced_native::image::Handle>
+        + 'a,
     Renderer::Theme: StyleSheet,
     SegmentedButton<'a, Variant, Message, Renderer>: SegmentedVariant<Renderer = Renderer>,
     Variant: 'static,
     Message: 'static + Clone,
 {
     fn from(mut widget: SegmentedButton<'a, Variant, Message, Renderer>) -> Self {
-        if widget.state.buttons.is_empty() {
+        if widget.state.is_empty() {
             widget.spacing = 0;
         }
 
@@ -344,4 +1115,74 @@ where
 struct UniqueWidgetState {
     /// The ID of the button that is being hovered. Defaults to null.
     hovered: Key,
+    /// The button that the cursor was pressed on, and where it was pressed.
+    pressed: Option<(Key, Point)>,
+    /// The button whose close button the cursor was pressed on.
+    close_pressed: Option<Key>,
+    /// Whether the pressed button is being dragged to another position.
+    dragging: bool,
+    /// How far the buttons are scrolled while they overflow.
+    offset: f32,
+    /// The active button when the buttons were last scrolled to show it.
+    revealed: Key,
+    /// Whether the widget receives keyboard input, after it was clicked or tabbed into.
+    is_focused: bool,
+    /// Whether the focus is outlined, which it is only after being focused by the keyboard.
+    is_focus_visible: bool,
+    /// The button which the keyboard navigates from, which is the active button if none.
+    focused: Option<Key>,
+}
+
+impl operation::Focusable for UniqueWidgetState {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.is_focus_visible = true;
+        self.focused = None;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The measured sizes of the parts of a button which are shown.
+struct ContentSize {
+    icon: Option<f32>,
+    text: Option<Size>,
+    badge: Option<Size>,
+    /// Spacing between each of the parts.
+    spacing: f32,
+}
+
+impl ContentSize {
+    fn width(&self) -> f32 {
+        let widths = [
+            self.icon,
+            self.text.map(|text| text.width),
+            self.badge.map(|badge| badge.width),
+        ];
+
+        let parts = widths.iter().flatten().count();
+        widths.iter().flatten().sum::<f32>() + parts.saturating_sub(1) as f32 * self.spacing
+    }
+
+    fn height(&self) -> f32 {
+        [
+            self.icon,
+            self.text.map(|text| text.height),
+            self.badge.map(|badge| badge.height),
+        ]
+        .iter()
+        .flatten()
+        .fold(0.0, |height, part| part.max(height))
+    }
+}
+
+/// Text size of the badges of buttons.
+fn badge_text_size() -> u16 {
+    crate::font::Typography::caption().size
 }
@@ -1,7 +1,7 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-use crate::{Element, Renderer};
+use crate::{theme, Element, Renderer};
 
 /// A setting within a settings view section.
 #[must_use]
@@ -18,11 +18,15 @@ pub fn item<'a, Message: 'static>(
 }
 
 /// A settings item aligned in a row
+///
+/// The row is padded for the standard density. Other densities may repad it with the
+/// [`Tokens`](theme::Tokens) of their theme.
 #[must_use]
 #[allow(clippy::module_name_repetitions)]
 pub fn item_row<Message>(children: Vec<Element<Message>>) -> iced::widget::Row<Message, Renderer> {
+    let tokens = theme::Density::Standard.tokens();
     iced::widget::row(children)
         .align_items(iced::Alignment::Center)
-        .padding([0, 18])
-        .spacing(12)
+        .padding([0, tokens.padding.settings_item])
+        .spacing(tokens.spacing.s)
 }
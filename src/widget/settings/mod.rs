@@ -7,11 +7,18 @@ mod section;
 pub use self::item::{item, item_row};
 pub use self::section::{view_section, Section};
 
-use crate::{Element, Renderer};
+use crate::{theme, Element, Renderer};
 use iced::widget::{column, Column};
 
 /// A column with a predefined style for creating a settings panel
+///
+/// The column is spaced for the standard density. Other densities may respace it with the
+/// [`Tokens`](theme::Tokens) of their theme.
 #[must_use]
 pub fn view_column<Message: 'static>(children: Vec<Element<Message>>) -> Column<Message, Renderer> {
-    column(children).spacing(24).padding([0, 24]).max_width(678)
+    let tokens = theme::Density::Standard.tokens();
+    column(children)
+        .spacing(tokens.spacing.l)
+        .padding([0, tokens.spacing.l])
+        .max_width(tokens.size.content_width)
 }
@@ -1,9 +1,10 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
+use crate::widget::text::heading;
 use crate::widget::ListColumn;
-use crate::Element;
-use iced::widget::{column, text};
+use crate::{theme, Element};
+use iced::widget::column;
 use std::borrow::Cow;
 
 /// A section within a settings view column.
@@ -12,12 +13,14 @@ pub fn view_section<'a, Message: 'static>(title: impl Into<Cow<'a, str>>) -> Sec
     Section {
         title: title.into(),
         children: ListColumn::default(),
+        density: theme::Density::default(),
     }
 }
 
 pub struct Section<'a, Message> {
     title: Cow<'a, str>,
     children: ListColumn<'a, Message>,
+    density: theme::Density,
 }
 
 impl<'a, Message: 'static> Section<'a, Message> {
@@ -27,14 +30,22 @@ impl<'a, Message: 'static> Section<'a, Message> {
         self.children = self.children.add(item.into());
         self
     }
+
+    /// Sets the density of the theme, which spaces the section and its list.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.children = self.children.density(density);
+        self.density = density;
+        self
+    }
 }
 
 impl<'a, Message: 'static> From<Section<'a, Message>> for Element<'a, Message> {
     fn from(data: Section<'a, Message>) -> Self {
-        let title = text(data.title).font(crate::font::FONT_SEMIBOLD).into();
+        let title = heading(data.title).into();
 
         column(vec![title, data.children.into_element()])
-            .spacing(8)
+            .spacing(data.density.tokens().spacing.xs)
             .into()
     }
 }
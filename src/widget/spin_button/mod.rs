@@ -16,6 +16,8 @@ use std::hash::Hash;
 
 pub struct SpinButton<T, Message> {
     value: T,
+    style: theme::Container,
+    density: theme::Density,
     on_change: Box<dyn Fn(SpinMessage) -> Message + 'static>,
 }
 
@@ -37,16 +39,38 @@ impl<T: 'static + Copy + Hash + ToString, Message: 'static> SpinButton<T, Messag
     pub fn new(value: T, on_change: impl Fn(SpinMessage) -> Message + 'static) -> Self {
         Self {
             on_change: Box::from(on_change),
+            style: theme::Container::Custom(container_style),
+            density: theme::Density::default(),
             value,
         }
     }
 
+    /// Sets the style of the container around the spin button.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<theme::Container>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the density of the theme, which sizes the spin button.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.density = density;
+        self
+    }
+
     pub fn into_element(self) -> Element<'static, Message> {
-        let Self { on_change, value } = self;
+        let Self {
+            on_change,
+            style,
+            density,
+            value,
+        } = self;
 
         Element::from(iced_lazy::lazy(
-            (value, crate::settings::default_icon_theme()),
+            (value, style, crate::settings::default_icon_theme(), density),
             move || -> Element<'static, SpinMessage> {
+                let control = density.tokens().size.control;
                 container(
                     row![
                         icon("list-remove-symbolic", 24)
@@ -82,14 +106,14 @@ impl<T: 'static + Copy + Hash + ToString, Message: 'static> SpinButton<T, Messag
                             .on_press(SpinMessage::Increment),
                     ]
                     .width(Length::Fill)
-                    .height(Length::Units(32))
+                    .height(Length::Units(control))
                     .align_items(Alignment::Center),
                 )
                 .padding([4, 4])
                 .align_y(Vertical::Center)
                 .width(Length::Units(95))
-                .height(Length::Units(32))
-                .style(theme::Container::Custom(container_style))
+                .height(Length::Units(control))
+                .style(style)
                 .into()
             },
         ))
@@ -112,7 +136,7 @@ fn container_style(theme: &crate::Theme) -> iced_style::container::Appearance {
     iced_style::container::Appearance {
         text_color: None,
         background: Some(Background::Color(secondary.component.base.into())),
-        border_radius: 24.0,
+        border_radius: theme.tokens().radius.round,
         border_width: 0.0,
         border_color: accent.base.into(),
     }
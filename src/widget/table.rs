@@ -0,0 +1,870 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A table which shows a slice of rows through typed columns.
+//!
+//! Columns may be sorted by clicking their headers, and resized by dragging the edges
+//! between them. The table grows to fit its rows, so it may be placed in a
+//! [`scrollable`](crate::widget::scrollable).
+
+use crate::widget::IconHandle;
+use crate::{theme, Element, Renderer};
+use iced::{Background, Color, Length, Point, Rectangle, Size};
+use iced_core::BorderRadius;
+use iced_native::event::{self, Event};
+use iced_native::widget::{tree, Operation, Tree};
+use iced_native::{layout, mouse, overlay, renderer, touch, Renderer as _};
+use iced_native::{Clipboard, Layout, Shell, Widget};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// Width of the dividers drawn between rows and columns.
+const DIVIDER_WIDTH: f32 = 1.0;
+
+/// Distance from the edge of a column within which dragging resizes the column.
+const RESIZE_HANDLE_WIDTH: f32 = 4.0;
+
+/// Size of the icon which indicates the sort order of a column.
+const SORT_INDICATOR_SIZE: u16 = 16;
+
+/// Creates a table which shows each of the `rows` through the columns added to it.
+#[must_use]
+pub fn table<'a, T, Message: 'static>(rows: &'a [T]) -> Table<'a, T, Message> {
+    Table::new(rows)
+}
+
+/// The order of the rows of a sorted column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// From the smallest value to the largest.
+    Ascending,
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+impl SortOrder {
+    /// The opposite order.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+/// A column of a [`Table`], which creates the cell of each row of type `T`.
+#[allow(missing_debug_implementations)]
+pub struct TableColumn<'a, T, Message> {
+    title: Cow<'a, str>,
+    view: Box<dyn Fn(&T) -> Element<'a, Message> + 'a>,
+    width: u16,
+    min_width: u16,
+    sortable: bool,
+}
+
+impl<'a, T, Message: 'static> TableColumn<'a, T, Message> {
+    /// Creates a column whose cells are created from each row by `view`.
+    #[must_use]
+    pub fn new(
+        title: impl Into<Cow<'a, str>>,
+        view: impl Fn(&T) -> Element<'a, Message> + 'a,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            view: Box::new(view),
+            width: 160,
+            min_width: 48,
+            sortable: false,
+        }
+    }
+
+    /// Creates a column whose cells show the text returned by `value` for each row.
+    #[must_use]
+    pub fn text(title: impl Into<Cow<'a, str>>, value: impl Fn(&T) -> String + 'a) -> Self {
+        Self::new(title, move |row| super::text::body(value(row)).into())
+    }
+
+    /// Sets the initial width of the column.
+    ///
+    /// The last column is widened to fill the remaining width of the table.
+    #[must_use]
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the width that the column cannot be resized below.
+    #[must_use]
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Whether clicking the header of the column emits [`Table::on_sort`].
+    #[must_use]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// A table which shows a slice of rows through typed columns.
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, T, Message> {
+    rows: &'a [T],
+    columns: Vec<Column>,
+    headers: Vec<Element<'a, Message>>,
+    /// The cells of every column, one column after another.
+    cells: Vec<Element<'a, Message>>,
+    selected: Vec<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    sort: Option<(usize, SortOrder)>,
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    striped: bool,
+    density: theme::Density,
+    /// Widths of the columns, which start at their declared widths and are copied from
+    /// the tree when the table is rebuilt.
+    widths: RefCell<Vec<f32>>,
+    /// Positions of the columns and rows from the last layout.
+    geometry: RefCell<Geometry>,
+    sort_ascending: IconHandle,
+    sort_descending: IconHandle,
+}
+
+impl<'a, T, Message: 'static> Table<'a, T, Message> {
+    /// Creates a table without columns, which shows each of the `rows`.
+    #[must_use]
+    pub fn new(rows: &'a [T]) -> Self {
+        Self {
+            rows,
+            columns: Vec::new(),
+            headers: Vec::new(),
+            cells: Vec::new(),
+            selected: Vec::new(),
+            on_select: None,
+            sort: None,
+            on_sort: None,
+            striped: false,
+            density: theme::Density::default(),
+            widths: RefCell::new(Vec::new()),
+            geometry: RefCell::new(Geometry::default()),
+            sort_ascending: IconHandle::named("pan-up-symbolic", SORT_INDICATOR_SIZE),
+            sort_descending: IconHandle::named("pan-down-symbolic", SORT_INDICATOR_SIZE),
+        }
+    }
+
+    /// Adds a column after the existing columns.
+    #[must_use]
+    pub fn column(mut self, column: TableColumn<'a, T, Message>) -> Self {
+        let header = super::text::body(column.title).font(crate::font::semibold());
+
+        self.headers.push(header.into());
+        self.cells
+            .extend(self.rows.iter().map(|row| (column.view)(row)));
+        self.widths.get_mut().push(f32::from(column.width));
+        self.columns.push(Column {
+            width: f32::from(column.width),
+            min_width: f32::from(column.min_width),
+            sortable: column.sortable,
+        });
+
+        self
+    }
+
+    /// The indices of the rows which are highlighted as selected.
+    #[must_use]
+    pub fn selected(mut self, selected: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = selected.into_iter().collect();
+        self
+    }
+
+    /// Makes the rows selectable, emitting the index of a row when it is clicked.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Shows the sort indicator on a column, which the rows are sorted by.
+    ///
+    /// The table shows the rows in the order they were given, so the application
+    /// should sort them before creating the table.
+    #[must_use]
+    pub fn sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Emits the column and order to sort by when the header of a sortable column is clicked.
+    ///
+    /// Clicking the column which is already sorted ascending sorts it descending.
+    #[must_use]
+    pub fn on_sort(mut self, on_sort: impl Fn(usize, SortOrder) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Whether every other row is shaded, which helps to follow wide rows.
+    #[must_use]
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Sets the density of the theme, which pads the cells.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    #[must_use]
+    pub fn into_element(self) -> Element<'a, Message>
+    where
+        T: 'a,
+    {
+        Element::new(self)
+    }
+
+    /// The headers and cells, in the order of their trees and layouts.
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message>> {
+        self.headers.iter().chain(&self.cells)
+    }
+
+    /// The column whose trailing edge is under the cursor, in the header row.
+    fn resize_handle_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        let geometry = self.geometry.borrow();
+
+        if cursor_position.y < bounds.y || cursor_position.y > bounds.y + geometry.header_height {
+            return None;
+        }
+
+        let mut x = bounds.x;
+        for (nth, width) in geometry.widths.iter().enumerate() {
+            x += width;
+
+            // The last column always fills the table, so it has no edge to drag.
+            if nth + 1 < geometry.widths.len()
+                && (cursor_position.x - x).abs() <= RESIZE_HANDLE_WIDTH
+            {
+                return Some(nth);
+            }
+        }
+
+        None
+    }
+
+    /// The column under the cursor, which may be in any row.
+    fn column_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let mut x = bounds.x;
+        self.geometry.borrow().widths.iter().position(|width| {
+            x += width;
+            cursor_position.x < x
+        })
+    }
+
+    /// Whether the cursor is over the header row.
+    fn is_over_header(&self, bounds: Rectangle, cursor_position: Point) -> bool {
+        bounds.contains(cursor_position)
+            && cursor_position.y < bounds.y + self.geometry.borrow().header_height
+    }
+
+    /// The row under the cursor, excluding the header.
+    fn row_at(&self, bounds: Rectangle, cursor_position: Point) -> Option<usize> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let y = cursor_position.y - bounds.y;
+        self.geometry
+            .borrow()
+            .rows
+            .iter()
+            .position(|&(top, height)| y >= top && y < top + height)
+    }
+}
+
+impl<'a, T, Message: 'static> Widget<Message, Renderer> for Table<'a, T, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.widths.resize(self.columns.len(), None);
+
+        self.widths.replace(
+            self.columns
+                .iter()
+                .zip(&state.widths)
+                .map(|(column, width)| width.unwrap_or(column.width))
+                .collect(),
+        );
+
+        tree.diff_children(&self.elements().collect::<Vec<_>>());
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let spacing = self.density.tokens().spacing;
+        let padding_x = f32::from(spacing.s);
+        let padding_y = f32::from(spacing.xs);
+        let sort_indicator = f32::from(SORT_INDICATOR_SIZE) + f32::from(spacing.xxs);
+
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let max = limits.max();
+
+        // The last column fills the remaining width of the table.
+        let mut widths = self.widths.borrow().clone();
+        if let Some((last, others)) = widths.split_last_mut() {
+            let remaining = max.width - others.iter().sum::<f32>();
+            if remaining.is_finite() && remaining > *last {
+                *last = remaining;
+            }
+        }
+
+        let cell_limits = |width: f32| {
+            layout::Limits::new(
+                Size::ZERO,
+                Size::new((width - 2.0 * padding_x).max(0.0), max.height),
+            )
+        };
+
+        let mut headers: Vec<layout::Node> = self
+            .headers
+            .iter()
+            .zip(&self.columns)
+            .zip(&widths)
+            .map(|((header, column), &width)| {
+                let width = if column.sortable {
+                    width - sort_indicator
+                } else {
+                    width
+                };
+
+                header.as_widget().layout(renderer, &cell_limits(width))
+            })
+            .collect();
+
+        let row_count = self.rows.len();
+        let mut cells: Vec<layout::Node> = self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(nth, cell)| {
+                let width = widths.get(nth / row_count.max(1)).copied().unwrap_or(0.0);
+                cell.as_widget().layout(renderer, &cell_limits(width))
+            })
+            .collect();
+
+        // Each row is as tall as its tallest cell.
+        let header_height = headers
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max)
+            + 2.0 * padding_y;
+
+        let mut rows = Vec::with_capacity(row_count);
+        let mut y = header_height + DIVIDER_WIDTH;
+        for row in 0..row_count {
+            let height = cells
+                .iter()
+                .skip(row)
+                .step_by(row_count)
+                .map(|node| node.size().height)
+                .fold(0.0, f32::max)
+                + 2.0 * padding_y;
+
+            if row != 0 {
+                y += DIVIDER_WIDTH;
+            }
+
+            rows.push((y, height));
+            y += height;
+        }
+
+        // Vertically center every cell within its row.
+        let mut x = 0.0;
+        for (column, &width) in widths.iter().enumerate() {
+            let header = &mut headers[column];
+            let top = (header_height - header.size().height) / 2.0;
+            header.move_to(Point::new(x + padding_x, top));
+
+            for (row, &(top, height)) in rows.iter().enumerate() {
+                let cell = &mut cells[column * row_count + row];
+                let top = top + (height - cell.size().height) / 2.0;
+                cell.move_to(Point::new(x + padding_x, top));
+            }
+
+            x += width;
+        }
+
+        self.geometry.replace(Geometry {
+            widths,
+            header_height,
+            rows,
+        });
+
+        let size = limits.resolve(Size::new(x, y));
+        headers.append(&mut cells);
+        layout::Node::with_children(size, headers)
+    }
+
+    fn operate(&self, tree: &mut Tree, layout: Layout<'_>, operation: &mut dyn Operation<Message>) {
+        operation.container(None, &mut |operation| {
+            self.elements()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child.as_widget().operate(state, layout, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        // Resizing takes precedence over the cells, which should not react to the drag.
+        if let Some(resize) = state.resize {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    let min_width = self.columns[resize.column].min_width;
+                    let width = (resize.width + cursor_position.x - resize.origin).max(min_width);
+
+                    state.widths[resize.column] = Some(width);
+                    self.widths.borrow_mut()[resize.column] = width;
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.resize = None;
+                    return event::Status::Captured;
+                }
+
+                _ => (),
+            }
+        }
+
+        let status = self
+            .headers
+            .iter_mut()
+            .chain(&mut self.cells)
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Some(column) = self.resize_handle_at(bounds, cursor_position) {
+                state.resize = Some(Resize {
+                    column,
+                    origin: cursor_position.x,
+                    width: self.geometry.borrow().widths[column],
+                });
+
+                return event::Status::Captured;
+            }
+
+            if self.is_over_header(bounds, cursor_position) {
+                let column = self.column_at(bounds, cursor_position);
+                if let (Some(column), Some(on_sort)) = (column, self.on_sort.as_ref()) {
+                    if self.columns[column].sortable {
+                        let order = match self.sort {
+                            Some((sorted, order)) if sorted == column => order.reverse(),
+                            _ => SortOrder::Ascending,
+                        };
+
+                        shell.publish(on_sort(column, order));
+                        return event::Status::Captured;
+                    }
+                }
+            } else if let Some(row) = self.row_at(bounds, cursor_position) {
+                if let Some(on_select) = self.on_select.as_ref() {
+                    shell.publish(on_select(row));
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if tree.state.downcast_ref::<State>().resize.is_some()
+            || self.resize_handle_at(bounds, cursor_position).is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let interaction = self
+            .elements()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::Idle {
+            return interaction;
+        }
+
+        let is_clickable = if self.is_over_header(bounds, cursor_position) {
+            self.on_sort.is_some()
+                && self
+                    .column_at(bounds, cursor_position)
+                    .map_or(false, |column| self.columns[column].sortable)
+        } else {
+            self.on_select.is_some() && self.row_at(bounds, cursor_position).is_some()
+        };
+
+        if is_clickable {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let geometry = self.geometry.borrow();
+        let cosmic = theme.cosmic();
+        let radius = theme.tokens().radius.m;
+        let divider: Color = cosmic.primary.divider.into();
+        let is_resizing = tree.state.downcast_ref::<State>().resize.is_some();
+
+        let fill = |renderer: &mut Renderer, bounds: Rectangle, radius: f32, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: BorderRadius::from(radius),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Background::Color(color),
+            );
+        };
+
+        fill(renderer, bounds, radius, cosmic.primary.base.into());
+
+        // Divide the header from the rows.
+        fill(
+            renderer,
+            Rectangle {
+                y: bounds.y + geometry.header_height,
+                height: DIVIDER_WIDTH,
+                ..bounds
+            },
+            0.0,
+            divider,
+        );
+
+        // Divide the headers from each other, which marks the edges that resize the columns.
+        let mut x = bounds.x;
+        for width in geometry
+            .widths
+            .iter()
+            .take(geometry.widths.len().saturating_sub(1))
+        {
+            x += width;
+            let inset = f32::from(theme.tokens().spacing.xs);
+            fill(
+                renderer,
+                Rectangle {
+                    x: x - DIVIDER_WIDTH / 2.0,
+                    y: bounds.y + inset,
+                    width: DIVIDER_WIDTH,
+                    height: (geometry.header_height - 2.0 * inset).max(0.0),
+                },
+                0.0,
+                divider,
+            );
+        }
+
+        let hovered_row = if is_resizing || self.on_select.is_none() {
+            None
+        } else {
+            self.row_at(bounds, cursor_position)
+        };
+
+        for (nth, &(top, height)) in geometry.rows.iter().enumerate() {
+            let row = Rectangle {
+                y: bounds.y + top,
+                height,
+                ..bounds
+            };
+
+            if nth != 0 {
+                fill(
+                    renderer,
+                    Rectangle {
+                        y: row.y - DIVIDER_WIDTH,
+                        height: DIVIDER_WIDTH,
+                        ..row
+                    },
+                    0.0,
+                    divider,
+                );
+            }
+
+            let background = if self.selected.contains(&nth) {
+                Some(cosmic.primary.component.selected.into())
+            } else if hovered_row == Some(nth) {
+                Some(cosmic.primary.component.hover.into())
+            } else if self.striped && nth % 2 == 1 {
+                Some(Color {
+                    a: divider.a / 2.0,
+                    ..divider
+                })
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                // Round the bottom corners of the last row to match the table.
+                let radius = if nth + 1 == geometry.rows.len() {
+                    [0.0, 0.0, radius, radius]
+                } else {
+                    [0.0; 4]
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        border_radius: BorderRadius::from(radius),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Background::Color(background),
+                );
+            }
+        }
+
+        let text_color: Color = cosmic.primary.on.into();
+        let selected_text_color: Color = cosmic.primary.component.selected_text.into();
+        let row_count = self.rows.len().max(1);
+        let mut children = self.elements().zip(&tree.children).zip(layout.children());
+
+        // Draw the headers, with the sort indicator beside the sorted column.
+        let mut x = bounds.x;
+        for (column, ((header, tree), layout)) in
+            children.by_ref().take(self.headers.len()).enumerate()
+        {
+            let width = geometry.widths.get(column).copied().unwrap_or(0.0);
+            let cell = Rectangle {
+                x,
+                y: bounds.y,
+                width,
+                height: geometry.header_height,
+            };
+
+            renderer.with_layer(cell, |renderer| {
+                header.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            });
+
+            if let Some((_, order)) = self.sort.filter(|&(sorted, _)| sorted == column) {
+                let size = f32::from(SORT_INDICATOR_SIZE);
+                let icon = match order {
+                    SortOrder::Ascending => &self.sort_ascending,
+                    SortOrder::Descending => &self.sort_descending,
+                };
+
+                icon.draw(
+                    renderer,
+                    Rectangle {
+                        x: x + width - f32::from(theme.tokens().spacing.s) - size,
+                        y: cell.center_y() - size / 2.0,
+                        width: size,
+                        height: size,
+                    },
+                    text_color,
+                );
+            }
+
+            x += width;
+        }
+
+        // Draw the cells, which are clipped to their columns when a column is narrowed.
+        for (nth, ((cell, tree), layout)) in children.enumerate() {
+            let (column, row) = (nth / row_count, nth % row_count);
+            let (top, height) = geometry.rows.get(row).copied().unwrap_or_default();
+            let left: f32 = geometry.widths.iter().take(column).sum();
+
+            let clip = Rectangle {
+                x: bounds.x + left,
+                y: bounds.y + top,
+                width: geometry.widths.get(column).copied().unwrap_or(0.0),
+                height,
+            };
+
+            if clip.intersection(viewport).is_none() {
+                continue;
+            }
+
+            let text_color = if self.selected.contains(&row) {
+                selected_text_color
+            } else {
+                text_color
+            };
+
+            renderer.with_layer(clip, |renderer| {
+                cell.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style { text_color },
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let elements: Vec<_> = self.elements().collect();
+
+        elements
+            .into_iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, state), layout)| {
+                child.as_widget().overlay(state, layout, renderer)
+            })
+            .next()
+    }
+}
+
+impl<'a, T: 'a, Message: 'static> From<Table<'a, T, Message>> for Element<'a, Message> {
+    fn from(table: Table<'a, T, Message>) -> Self {
+        table.into_element()
+    }
+}
+
+/// Properties of a column which are kept after its cells are created.
+struct Column {
+    width: f32,
+    min_width: f32,
+    sortable: bool,
+}
+
+/// Positions of the columns and rows, relative to the table.
+#[derive(Default)]
+struct Geometry {
+    /// The width of each column, including the last column which fills the table.
+    widths: Vec<f32>,
+    /// The height of the header row.
+    header_height: f32,
+    /// The top and height of each row beneath the header.
+    rows: Vec<(f32, f32)>,
+}
+
+/// State that is maintained by each table.
+#[derive(Default)]
+struct State {
+    /// Widths of the columns which were resized by the user.
+    widths: Vec<Option<f32>>,
+    /// The column that is being resized.
+    resize: Option<Resize>,
+}
+
+/// A column being resized by dragging its trailing edge.
+#[derive(Clone, Copy)]
+struct Resize {
+    column: usize,
+    /// The horizontal position of the cursor when the drag began.
+    origin: f32,
+    /// The width of the column when the drag began.
+    width: f32,
+}
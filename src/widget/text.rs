@@ -0,0 +1,52 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Text widgets which are styled by the [`Typography`] scale.
+
+use crate::font::Typography;
+use crate::Renderer;
+use iced::widget::{self, Text};
+
+/// Text with the font and size of a [`Typography`].
+#[must_use]
+pub fn typography<'a>(typography: Typography, content: impl ToString) -> Text<'a, Renderer> {
+    widget::text(content)
+        .font(typography.font)
+        .size(typography.size)
+}
+
+/// Large text for hero headers and empty states.
+#[must_use]
+pub fn display<'a>(content: impl ToString) -> Text<'a, Renderer> {
+    typography(Typography::display(), content)
+}
+
+/// The title of a page.
+#[must_use]
+pub fn title<'a>(content: impl ToString) -> Text<'a, Renderer> {
+    typography(Typography::title(), content)
+}
+
+/// The header of a section.
+#[must_use]
+pub fn heading<'a>(content: impl ToString) -> Text<'a, Renderer> {
+    typography(Typography::heading(), content)
+}
+
+/// Text for labels and paragraphs.
+#[must_use]
+pub fn body<'a>(content: impl ToString) -> Text<'a, Renderer> {
+    typography(Typography::body(), content)
+}
+
+/// Small text for descriptions and annotations.
+#[must_use]
+pub fn caption<'a>(content: impl ToString) -> Text<'a, Renderer> {
+    typography(Typography::caption(), content)
+}
+
+/// Fixed-width text for code and terminal output.
+#[must_use]
+pub fn monospace<'a>(content: impl ToString) -> Text<'a, Renderer> {
+    typography(Typography::monospace(), content)
+}
@@ -0,0 +1,72 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A widget showing a hierarchy of nodes which may be expanded and collapsed.
+//!
+//! ## Example
+//!
+//! Add the state and message variants in your application.
+//!
+//! ```ignore
+//! use cosmic::widget::tree_view;
+//!
+//! enum AppMessage {
+//!     Selected(tree_view::Key),
+//!     Toggled(tree_view::Key, bool),
+//! }
+//!
+//! struct App {
+//!     ...
+//!     folders: tree_view::State<PathBuf>,
+//!     ...
+//! }
+//! ```
+//!
+//! Then add the nodes at the top of the tree. Nodes whose children are loaded later are
+//! marked as expandable.
+//!
+//! ```ignore
+//! let home = application.folders.insert(None, "Home", home_dir);
+//! application.folders.set_expandable(home, true);
+//! ```
+//!
+//! Update the state when a node is selected or toggled, and load the children of a node
+//! the first time that it is expanded.
+//!
+//! ```ignore
+//! match message {
+//!     AppMessage::Selected(key) => self.folders.select(key),
+//!     AppMessage::Toggled(key, expanded) => {
+//!         if expanded && self.folders.children(key).is_empty() {
+//!             for (name, path) in read_folders(&self.folders.data(key)) {
+//!                 self.folders.insert(Some(key), name, path);
+//!             }
+//!         }
+//!
+//!         self.folders.set_expanded(key, expanded);
+//!     }
+//! }
+//! ```
+//!
+//! Then create the widget in the view method.
+//!
+//! ```ignore
+//! let widget = tree_view(&application.folders)
+//!     .on_select(AppMessage::Selected)
+//!     .on_toggle(AppMessage::Toggled);
+//! ```
+
+mod state;
+mod widget;
+
+pub use self::state::{Key, Node, NodeContent, SecondaryState, SharedWidgetState, State};
+pub use self::widget::TreeView;
+
+/// A hierarchy of nodes, whose children are shown beneath them when expanded.
+///
+/// The widget can be focused by clicking a node, after which the arrow keys select the
+/// previous and next nodes, Left collapses a node, and Right expands it.
+#[must_use]
+pub fn tree_view<Message: 'static, Data>(state: &State<Data>) -> TreeView<'_, Message> {
+    TreeView::new(&state.inner)
+}
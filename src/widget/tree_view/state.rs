@@ -0,0 +1,284 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+use slotmap::{SecondaryMap, SlotMap};
+use std::borrow::Cow;
+
+slotmap::new_key_type! {
+    /// An ID for a node of a tree view
+    pub struct Key;
+}
+
+/// Contains all state for interacting with a tree view.
+pub struct State<Data> {
+    /// State that is shared with widget drawing.
+    pub inner: SharedWidgetState,
+
+    /// State unique to the application.
+    pub data: SecondaryState<Data>,
+}
+
+impl<Data> Default for State<Data> {
+    fn default() -> Self {
+        Self {
+            inner: SharedWidgetState::default(),
+            data: SecondaryState::default(),
+        }
+    }
+}
+
+/// State which is most useful to the widget.
+#[derive(Default)]
+pub struct SharedWidgetState {
+    /// The content and hierarchy of every node.
+    pub nodes: SlotMap<Key, Node>,
+
+    /// The nodes at the top of the tree, in the order they are shown.
+    pub roots: Vec<Key>,
+
+    /// The selected node, if any.
+    pub selected: Option<Key>,
+}
+
+/// State which is most useful to the application.
+pub type SecondaryState<Data> = SecondaryMap<Key, Data>;
+
+impl SharedWidgetState {
+    /// The nodes which are shown, in order, with their depth in the tree.
+    ///
+    /// Children are only shown while every one of their ancestors is expanded.
+    pub(super) fn visible(&self) -> Vec<(Key, usize)> {
+        let mut visible = Vec::with_capacity(self.nodes.len());
+        let mut stack: Vec<(Key, usize)> = self.roots.iter().rev().map(|&key| (key, 0)).collect();
+
+        while let Some((key, depth)) = stack.pop() {
+            if let Some(node) = self.nodes.get(key) {
+                visible.push((key, depth));
+
+                if node.expanded {
+                    stack.extend(node.children.iter().rev().map(|&child| (child, depth + 1)));
+                }
+            }
+        }
+
+        visible
+    }
+}
+
+impl<Data> State<Data> {
+    /// The ID of the selected node.
+    #[must_use]
+    pub fn selected(&self) -> Option<Key> {
+        self.inner.selected
+    }
+
+    /// Get the application data for the selected node.
+    #[must_use]
+    pub fn selected_data(&self) -> Option<&Data> {
+        self.selected().and_then(|key| self.data(key))
+    }
+
+    /// Get the application data for a node.
+    #[must_use]
+    pub fn data(&self, key: Key) -> Option<&Data> {
+        self.data.get(key)
+    }
+
+    /// Get the content of a node.
+    #[must_use]
+    pub fn node(&self, key: Key) -> Option<&Node> {
+        self.inner.nodes.get(key)
+    }
+
+    /// The children of a node, which are empty until they are loaded.
+    #[must_use]
+    pub fn children(&self, key: Key) -> &[Key] {
+        self.inner
+            .nodes
+            .get(key)
+            .map_or(&[], |node| node.children.as_slice())
+    }
+
+    /// Insert a new node at the end of the children of `parent`, or at the top of the tree.
+    pub fn insert(
+        &mut self,
+        parent: Option<Key>,
+        content: impl Into<NodeContent>,
+        data: Data,
+    ) -> Key {
+        let key = self.inner.nodes.insert(Node {
+            content: content.into(),
+            parent,
+            children: Vec::new(),
+            expanded: false,
+            expandable: false,
+        });
+
+        match parent.and_then(|parent| self.inner.nodes.get_mut(parent)) {
+            Some(parent) => parent.children.push(key),
+            None => self.inner.roots.push(key),
+        }
+
+        self.data.insert(key, data);
+        key
+    }
+
+    /// Removes a node along with all of its descendants.
+    pub fn remove(&mut self, key: Key) -> Option<Data> {
+        let node = self.inner.nodes.remove(key)?;
+
+        match node
+            .parent
+            .and_then(|parent| self.inner.nodes.get_mut(parent))
+        {
+            Some(parent) => parent.children.retain(|&child| child != key),
+            None => self.inner.roots.retain(|&root| root != key),
+        }
+
+        self.remove_descendants(&node.children);
+
+        if self.inner.selected == Some(key) {
+            self.inner.selected = None;
+        }
+
+        self.data.remove(key)
+    }
+
+    /// Removes the children of a node, so that they may be loaded again when expanded.
+    pub fn clear_children(&mut self, key: Key) {
+        if let Some(node) = self.inner.nodes.get_mut(key) {
+            let children = std::mem::take(&mut node.children);
+            self.remove_descendants(&children);
+        }
+    }
+
+    /// Marks a node as having children which are loaded when it is first expanded.
+    ///
+    /// The node shows a chevron before its children are inserted. Expanding it emits
+    /// [`TreeView::on_toggle`](super::TreeView::on_toggle), which is when the application
+    /// should insert them.
+    pub fn set_expandable(&mut self, key: Key, expandable: bool) {
+        if let Some(node) = self.inner.nodes.get_mut(key) {
+            node.expandable = expandable;
+        }
+    }
+
+    /// Whether the children of a node are shown.
+    #[must_use]
+    pub fn is_expanded(&self, key: Key) -> bool {
+        self.inner
+            .nodes
+            .get(key)
+            .map_or(false, |node| node.expanded)
+    }
+
+    /// Shows or hides the children of a node.
+    ///
+    /// If a descendant of a collapsed node was selected, the node is selected instead.
+    pub fn set_expanded(&mut self, key: Key, expanded: bool) {
+        if let Some(node) = self.inner.nodes.get_mut(key) {
+            node.expanded = expanded;
+        }
+
+        if !expanded {
+            if let Some(selected) = self.inner.selected {
+                if self.is_descendant(selected, key) {
+                    self.inner.selected = Some(key);
+                }
+            }
+        }
+    }
+
+    /// Selects this node.
+    pub fn select(&mut self, key: Key) {
+        self.inner.selected = Some(key);
+    }
+
+    /// Clears the selection.
+    pub fn deselect(&mut self) {
+        self.inner.selected = None;
+    }
+
+    /// Whether `key` is below `ancestor` in the tree.
+    fn is_descendant(&self, key: Key, ancestor: Key) -> bool {
+        let mut parent = self.inner.nodes.get(key).and_then(|node| node.parent);
+
+        while let Some(key) = parent {
+            if key == ancestor {
+                return true;
+            }
+
+            parent = self.inner.nodes.get(key).and_then(|node| node.parent);
+        }
+
+        false
+    }
+
+    fn remove_descendants(&mut self, children: &[Key]) {
+        let mut stack = children.to_vec();
+
+        while let Some(key) = stack.pop() {
+            if let Some(node) = self.inner.nodes.remove(key) {
+                stack.extend(node.children);
+            }
+
+            if self.inner.selected == Some(key) {
+                self.inner.selected = None;
+            }
+
+            self.data.remove(key);
+        }
+    }
+}
+
+/// A node of a tree view, and its place in the tree.
+pub struct Node {
+    pub content: NodeContent,
+
+    /// The node that this node is a child of.
+    pub parent: Option<Key>,
+
+    /// The children of this node, in the order they are shown.
+    pub children: Vec<Key>,
+
+    /// Whether the children of this node are shown.
+    pub expanded: bool,
+
+    /// Whether this node has children which have not been loaded yet.
+    pub expandable: bool,
+}
+
+impl Node {
+    /// Whether this node shows a chevron to expand or collapse it.
+    #[must_use]
+    pub fn has_children(&self) -> bool {
+        self.expandable || !self.children.is_empty()
+    }
+}
+
+/// Data to be drawn in a node of a tree view.
+pub struct NodeContent {
+    pub text: Cow<'static, str>,
+}
+
+impl From<String> for NodeContent {
+    fn from(text: String) -> Self {
+        NodeContent {
+            text: Cow::Owned(text),
+        }
+    }
+}
+
+impl From<&'static str> for NodeContent {
+    fn from(text: &'static str) -> Self {
+        NodeContent {
+            text: Cow::Borrowed(text),
+        }
+    }
+}
+
+impl From<Cow<'static, str>> for NodeContent {
+    fn from(text: Cow<'static, str>) -> Self {
+        NodeContent { text }
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+use super::state::{Key, SharedWidgetState};
+use crate::font::Typography;
+use crate::widget::IconHandle;
+use crate::{theme, Element, Renderer};
+use iced::{alignment, Background, Color, Length, Point, Rectangle, Size};
+use iced_core::BorderRadius;
+use iced_native::event::{self, Event};
+use iced_native::widget::{tree, Tree};
+use iced_native::{keyboard, layout, mouse, renderer, touch, Renderer as _};
+use iced_native::{text::Renderer as _, Clipboard, Layout, Shell, Widget};
+
+/// Size of the chevron which indicates whether a node is expanded.
+const CHEVRON_SIZE: u16 = 16;
+
+/// A hierarchy of nodes, whose children are shown beneath them when expanded.
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Message> {
+    /// Contains application state also used for drawing.
+    state: &'a SharedWidgetState,
+    /// The nodes which are shown with their depth, found once since the state is borrowed.
+    visible: Vec<(Key, usize)>,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    width: Length,
+    density: theme::Density,
+    chevron_collapsed: IconHandle,
+    chevron_expanded: IconHandle,
+}
+
+impl<'a, Message: 'static> TreeView<'a, Message> {
+    #[must_use]
+    pub fn new(state: &'a SharedWidgetState) -> Self {
+        Self {
+            state,
+            visible: state.visible(),
+            on_select: None,
+            on_toggle: None,
+            width: Length::Fill,
+            density: theme::Density::default(),
+            chevron_collapsed: IconHandle::named("go-next-symbolic", CHEVRON_SIZE),
+            chevron_expanded: IconHandle::named("go-down-symbolic", CHEVRON_SIZE),
+        }
+    }
+
+    /// Emits the ID of a node when it is clicked, or navigated to with the keyboard.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Emits the ID of a node and whether it should be expanded, when its chevron is clicked.
+    ///
+    /// The application should call [`State::set_expanded`](super::State::set_expanded), and
+    /// insert the children of an expandable node if they have not been loaded yet.
+    #[must_use]
+    pub fn on_toggle(mut self, on_toggle: impl Fn(Key, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the width of the tree view.
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the density of the theme, which sizes and indents the rows.
+    #[must_use]
+    pub fn density(mut self, density: theme::Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    fn row_height(&self) -> f32 {
+        f32::from(self.density.tokens().size.control)
+    }
+
+    /// The bounds of the chevron of a node at the given depth, within its row.
+    fn chevron_bounds(&self, row: Rectangle, depth: usize) -> Rectangle {
+        let spacing = self.density.tokens().spacing;
+        let size = f32::from(CHEVRON_SIZE);
+
+        Rectangle {
+            x: row.x + f32::from(spacing.s) + depth as f32 * f32::from(spacing.l),
+            y: row.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+
+    /// The visible node under the cursor, along with its row and depth.
+    fn node_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<(Key, Rectangle, usize)> {
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let row_height = self.row_height();
+        let nth = ((cursor_position.y - bounds.y) / row_height) as usize;
+
+        self.visible.get(nth).map(|&(key, depth)| {
+            let row = Rectangle {
+                y: bounds.y + nth as f32 * row_height,
+                height: row_height,
+                ..bounds
+            };
+
+            (key, row, depth)
+        })
+    }
+
+    fn select(&self, key: Key, shell: &mut Shell<'_, Message>) {
+        if self.state.selected != Some(key) {
+            if let Some(on_select) = self.on_select.as_ref() {
+                shell.publish(on_select(key));
+            }
+        }
+    }
+
+    fn toggle(&self, key: Key, expanded: bool, shell: &mut Shell<'_, Message>) {
+        if let Some(on_toggle) = self.on_toggle.as_ref() {
+            shell.publish(on_toggle(key, expanded));
+        }
+    }
+
+    /// Handles a key press, moving the selection between the visible nodes.
+    fn navigate(
+        &self,
+        key_code: keyboard::KeyCode,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let visible = &self.visible;
+        let last = match visible.len().checked_sub(1) {
+            Some(last) => last,
+            None => return event::Status::Ignored,
+        };
+
+        let current = self
+            .state
+            .selected
+            .and_then(|selected| visible.iter().position(|&(key, _)| key == selected));
+
+        let selected = match current {
+            Some(current) => visible[current].0,
+            None => {
+                return match key_code {
+                    keyboard::KeyCode::Up | keyboard::KeyCode::End => {
+                        self.select(visible[last].0, shell);
+                        event::Status::Captured
+                    }
+                    keyboard::KeyCode::Down | keyboard::KeyCode::Home => {
+                        self.select(visible[0].0, shell);
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                };
+            }
+        };
+
+        let node = match self.state.nodes.get(selected) {
+            Some(node) => node,
+            None => return event::Status::Ignored,
+        };
+
+        let current = current.unwrap_or(0);
+
+        match key_code {
+            keyboard::KeyCode::Up => self.select(visible[current.saturating_sub(1)].0, shell),
+            keyboard::KeyCode::Down => self.select(visible[(current + 1).min(last)].0, shell),
+            keyboard::KeyCode::Home => self.select(visible[0].0, shell),
+            keyboard::KeyCode::End => self.select(visible[last].0, shell),
+
+            // Left collapses an expanded node, or moves to the parent of a collapsed node.
+            keyboard::KeyCode::Left => {
+                if node.expanded && node.has_children() {
+                    self.toggle(selected, false, shell);
+                } else if let Some(parent) = node.parent {
+                    self.select(parent, shell);
+                }
+            }
+
+            // Right expands a collapsed node, or moves to the first child of an expanded node.
+            keyboard::KeyCode::Right => {
+                if !node.expanded && node.has_children() {
+                    self.toggle(selected, true, shell);
+                } else if let Some(&child) = node.children.first() {
+                    self.select(child, shell);
+                }
+            }
+
+            keyboard::KeyCode::Enter | keyboard::KeyCode::Space if node.has_children() => {
+                self.toggle(selected, !node.expanded, shell);
+            }
+
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+}
+
+impl<'a, Message: 'static> Widget<Message, Renderer> for TreeView<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let height = self.visible.len() as f32 * self.row_height();
+
+        layout::Node::new(limits.resolve(Size::new(limits.max().width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = bounds.contains(cursor_position);
+
+                if let Some((key, row, depth)) = self.node_at(bounds, cursor_position) {
+                    let node = &self.state.nodes[key];
+                    let padding = f32::from(self.density.tokens().spacing.xxs);
+                    let chevron = self.chevron_bounds(row, depth);
+
+                    // Pad the chevron to make it easier to click.
+                    let is_on_chevron = cursor_position.x >= chevron.x - padding
+                        && cursor_position.x <= chevron.x + chevron.width + padding;
+
+                    if is_on_chevron && node.has_children() {
+                        self.toggle(key, !node.expanded, shell);
+                    } else {
+                        self.select(key, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if state.is_focused => {
+                return self.navigate(key_code, shell);
+            }
+
+            _ => (),
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.node_at(layout.bounds(), cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cosmic = theme.cosmic();
+        let radius = theme.tokens().radius.s;
+        let typography = Typography::body();
+        let row_height = self.row_height();
+        let hovered = self.node_at(bounds, cursor_position).map(|(key, ..)| key);
+
+        for (nth, &(key, depth)) in self.visible.iter().enumerate() {
+            let row = Rectangle {
+                y: bounds.y + nth as f32 * row_height,
+                height: row_height,
+                ..bounds
+            };
+
+            if row.intersection(viewport).is_none() {
+                continue;
+            }
+
+            let node = &self.state.nodes[key];
+            let is_selected = self.state.selected == Some(key);
+
+            let background = if is_selected {
+                Some(cosmic.primary.component.selected)
+            } else if hovered == Some(key) {
+                Some(cosmic.primary.component.hover)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        border_radius: BorderRadius::from(radius),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Background::Color(background.into()),
+                );
+            }
+
+            // Outline the selected node while the keyboard is focused on the tree.
+            if is_selected && state.is_focused {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        border_radius: BorderRadius::from(radius),
+                        border_width: if theme.is_high_contrast() { 3.0 } else { 2.0 },
+                        border_color: cosmic.accent.focus.into(),
+                    },
+                    Background::Color(Color::TRANSPARENT),
+                );
+            }
+
+            let text_color = if is_selected {
+                cosmic.primary.component.selected_text.into()
+            } else {
+                style.text_color
+            };
+
+            let chevron = self.chevron_bounds(row, depth);
+            if node.has_children() {
+                let icon = if node.expanded {
+                    &self.chevron_expanded
+                } else {
+                    &self.chevron_collapsed
+                };
+
+                icon.draw(renderer, chevron, text_color);
+            }
+
+            // Leaves are aligned with the text of their siblings which have chevrons.
+            let x = chevron.x + chevron.width + f32::from(theme.tokens().spacing.xs);
+            renderer.fill_text(iced_native::text::Text {
+                content: &node.content.text,
+                size: f32::from(typography.size),
+                bounds: Rectangle {
+                    x,
+                    y: row.center_y(),
+                    width: (row.x + row.width - x).max(0.0),
+                    ..row
+                },
+                color: text_color,
+                font: typography.font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
+
+impl<'a, Message: 'static> From<TreeView<'a, Message>> for Element<'a, Message> {
+    fn from(tree_view: TreeView<'a, Message>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// State that is maintained by each tree view.
+#[derive(Default)]
+struct State {
+    /// Whether the tree receives keyboard input, after one of its nodes was clicked.
+    is_focused: bool,
+}
@@ -0,0 +1,101 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+use cosmic::iced::Color;
+use cosmic::theme::{self, contrast, Theme};
+
+fn assert_readable(theme: &Theme, threshold: f32) {
+    let issues = contrast::audit(theme, threshold);
+
+    assert!(
+        issues.is_empty(),
+        "{:?} theme has low contrast styles:\n{}",
+        theme,
+        issues
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[test]
+fn themes_meet_their_required_contrast() {
+    for theme in [
+        Theme::Light,
+        Theme::Dark,
+        Theme::HighContrastLight,
+        Theme::HighContrastDark,
+    ] {
+        assert_readable(&theme, contrast::required(&theme));
+    }
+}
+
+#[test]
+fn high_contrast_themes_have_more_contrast() {
+    for (normal, high_contrast) in [
+        (Theme::Light, Theme::HighContrastLight),
+        (Theme::Dark, Theme::HighContrastDark),
+    ] {
+        let (normal, high_contrast) = (
+            contrast::minimum(&normal),
+            contrast::minimum(&high_contrast),
+        );
+
+        assert!(
+            high_contrast > normal,
+            "high contrast minimum of {high_contrast:.2}:1 is not above {normal:.2}:1"
+        );
+    }
+}
+
+#[test]
+fn generated_accents_are_readable() {
+    let seeds = [
+        Color::from_rgb8(0xff, 0xd7, 0x00),
+        Color::from_rgb8(0x00, 0x1f, 0x5c),
+        Color::from_rgb8(0x94, 0xeb, 0xeb),
+        Color::from_rgb8(0xe0, 0x1b, 0x24),
+    ];
+
+    for base in [Theme::Light, Theme::Dark] {
+        for seed in seeds {
+            let theme = base.with_accent(seed);
+            let accent = &theme.cosmic().accent;
+            let background: Color = theme.cosmic().bg_color().into();
+
+            assert!(contrast::ratio(accent.base.into(), accent.on.into()) >= contrast::WCAG_AA);
+            assert!(contrast::ratio(accent.base.into(), background) >= contrast::WCAG_AA);
+        }
+    }
+}
+
+#[test]
+fn accents_keep_high_contrast() {
+    let seeds = [
+        Color::from_rgb8(0xff, 0xd7, 0x00),
+        Color::from_rgb8(0x00, 0x1f, 0x5c),
+    ];
+
+    for base in [Theme::HighContrastLight, Theme::HighContrastDark] {
+        for seed in seeds {
+            let theme = base.with_accent(seed);
+
+            assert!(theme.is_high_contrast());
+            assert_readable(&theme, contrast::WCAG_AAA);
+        }
+    }
+}
+
+#[test]
+fn audit_reports_accent_on_accent() {
+    let mut cosmic = theme::COSMIC_DARK.clone();
+    cosmic.accent.on = cosmic.accent.base;
+    let theme = Theme::custom(cosmic);
+
+    let issues = contrast::audit(&theme, contrast::WCAG_AA);
+
+    assert!(issues
+        .iter()
+        .any(|issue| issue.style == "Button::Primary" && issue.state == "active"));
+}
@@ -0,0 +1,191 @@
+// Copyright 2023 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+#![cfg(feature = "softbuffer")]
+
+use cosmic::iced::widget::{button, text};
+use cosmic::iced::{Point, Rectangle, Size};
+use cosmic::iced_native::event::Event;
+use cosmic::iced_native::widget::Tree;
+use cosmic::iced_native::{clipboard, keyboard, layout, mouse, Layout, Shell};
+use cosmic::widget::{list_column, table, tree_view, TableColumn};
+use cosmic::{Element, Renderer};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Message {
+    Pressed(usize),
+    Reorder(usize, usize),
+    Select(tree_view::Key),
+    Toggle(tree_view::Key, bool),
+}
+
+fn renderer() -> Renderer {
+    Renderer::new(iced_softbuffer::Backend::new())
+}
+
+fn layout<Message>(element: &Element<'_, Message>, renderer: &Renderer) -> layout::Node {
+    let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 600.0));
+    element.as_widget().layout(renderer, &limits)
+}
+
+/// Sends each event to the element with the cursor at its position.
+fn send<Message>(
+    element: &mut Element<'_, Message>,
+    tree: &mut Tree,
+    node: &layout::Node,
+    renderer: &Renderer,
+    events: &[(Event, Point)],
+) -> Vec<Message> {
+    let mut messages = Vec::new();
+
+    {
+        let mut shell = Shell::new(&mut messages);
+
+        for (event, cursor_position) in events {
+            element.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                Layout::new(node),
+                *cursor_position,
+                renderer,
+                &mut clipboard::Null,
+                &mut shell,
+            );
+        }
+    }
+
+    messages
+}
+
+#[test]
+fn dropping_a_row_onto_a_button_only_reorders() {
+    let renderer = renderer();
+    let mut column: Element<'_, Message> = (0..3)
+        .fold(list_column().on_reorder(Message::Reorder), |column, nth| {
+            column.add(button(text(nth)).on_press(Message::Pressed(nth)))
+        })
+        .into();
+
+    let mut tree = Tree::new(&column);
+    let node = layout(&column, &renderer);
+    let rows: Vec<Rectangle> = Layout::new(&node)
+        .children()
+        .map(|row| row.bounds())
+        .collect();
+
+    // The drag handle of a row is 16px wide, and ends 6px before its content.
+    let handle = Point::new(rows[0].x - 14.0, rows[0].center_y());
+    let button = Point::new(rows[2].center_x(), rows[2].center_y() + 1.0);
+
+    let messages = send(
+        &mut column,
+        &mut tree,
+        &node,
+        &renderer,
+        &[
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                handle,
+            ),
+            (
+                Event::Mouse(mouse::Event::CursorMoved { position: button }),
+                button,
+            ),
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                button,
+            ),
+        ],
+    );
+
+    assert_eq!(messages, [Message::Reorder(0, 2)]);
+}
+
+#[test]
+fn new_tables_lay_out_columns_at_their_widths() {
+    let renderer = renderer();
+    let rows = ["a", "b"];
+    let element: Element<'_, Message> = table(&rows)
+        .column(TableColumn::text("First", ToString::to_string).width(120))
+        .column(TableColumn::text("Second", ToString::to_string).width(80))
+        .column(TableColumn::text("Third", ToString::to_string))
+        .into();
+
+    let node = layout(&element, &renderer);
+    let headers: Vec<f32> = Layout::new(&node)
+        .children()
+        .take(3)
+        .map(|header| header.bounds().x)
+        .collect();
+
+    assert_eq!(headers[1] - headers[0], 120.0);
+    assert_eq!(headers[2] - headers[1], 80.0);
+}
+
+fn key_press(key_code: keyboard::KeyCode) -> (Event, Point) {
+    let event = Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code,
+        modifiers: keyboard::Modifiers::empty(),
+    });
+
+    (event, Point::ORIGIN)
+}
+
+#[test]
+fn tree_view_keys_expand_collapse_and_move_the_selection() {
+    let renderer = renderer();
+    let mut folders = tree_view::State::default();
+    let home = folders.insert(None, "Home", ());
+    let documents = folders.insert(Some(home), "Documents", ());
+    let media = folders.insert(None, "Media", ());
+    folders.set_expanded(home, true);
+
+    // Each node is pressed away from its chevron to focus the tree, then sent keys.
+    let mut press = |selected, row: f32, keys: &[keyboard::KeyCode]| {
+        folders.select(selected);
+
+        let mut element: Element<'_, Message> = tree_view(&folders)
+            .on_select(Message::Select)
+            .on_toggle(Message::Toggle)
+            .into();
+
+        let mut tree = Tree::new(&element);
+        let node = layout(&element, &renderer);
+        let row_height = node.bounds().height / 3.0;
+        let position = Point::new(200.0, (row + 0.5) * row_height);
+
+        let mut events = vec![(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            position,
+        )];
+        events.extend(keys.iter().map(|&key_code| key_press(key_code)));
+
+        send(&mut element, &mut tree, &node, &renderer, &events)
+    };
+
+    assert_eq!(
+        press(
+            home,
+            0.0,
+            &[keyboard::KeyCode::Left, keyboard::KeyCode::Right]
+        ),
+        [Message::Toggle(home, false), Message::Select(documents)]
+    );
+
+    assert_eq!(
+        press(
+            documents,
+            1.0,
+            &[
+                keyboard::KeyCode::Left,
+                keyboard::KeyCode::Home,
+                keyboard::KeyCode::End
+            ]
+        ),
+        [
+            Message::Select(home),
+            Message::Select(home),
+            Message::Select(media)
+        ]
+    );
+}